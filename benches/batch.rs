@@ -0,0 +1,121 @@
+//! Compares throughput of the batch functions with calling the scalar functions row by row
+//! over the same input slices, writing into the same output slice.
+//!
+//! Run with `cargo bench`, add `RUSTFLAGS="-C target-cpu=native"` to let the compiler use
+//! vector rounding instructions.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use meteo_tools::{batch, PressureUnit};
+
+const ROWS: usize = 1_000_000;
+const ROUNDS: u32 = 10;
+
+fn measure(mut run: impl FnMut()) -> Duration {
+    run();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        run();
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn report(name: &str, scalar: Duration, batch: Duration) {
+    let throughput = |duration: Duration| ROWS as f64 / duration.as_secs_f64() / 1_000_000.0;
+    println!(
+        "{:<26} scalar {:>8.1} Mrows/s   batch {:>8.1} Mrows/s   speedup {:.2}x",
+        name,
+        throughput(scalar),
+        throughput(batch),
+        scalar.as_secs_f64() / batch.as_secs_f64()
+    );
+}
+
+fn main() {
+    let temperatures: Vec<f64> = (0..ROWS)
+        .map(|row| -20.0 + (row % 600) as f64 * 0.1)
+        .collect();
+    let relative_humidities: Vec<f64> = (0..ROWS)
+        .map(|row| 5.0 + (row % 950) as f64 * 0.1)
+        .collect();
+    let pressures: Vec<f64> = (0..ROWS)
+        .map(|row| 950.0 + (row % 800) as f64 * 0.1)
+        .collect();
+    let mut output = vec![0.0; ROWS];
+
+    let scalar = measure(|| {
+        for row in 0..ROWS {
+            output[row] = meteo_tools::common_celsius_dew_point(
+                &temperatures[row],
+                &relative_humidities[row],
+            );
+        }
+        black_box(&output);
+    });
+    let batch = measure(|| {
+        batch::common_celsius_dew_point(&temperatures, &relative_humidities, &mut output);
+        black_box(&output);
+    });
+    report("common_celsius_dew_point", scalar, batch);
+
+    let scalar = measure(|| {
+        for row in 0..ROWS {
+            output[row] =
+                meteo_tools::celsius_heat_index(&temperatures[row], &relative_humidities[row]);
+        }
+        black_box(&output);
+    });
+    let batch = measure(|| {
+        batch::celsius_heat_index(&temperatures, &relative_humidities, &mut output);
+        black_box(&output);
+    });
+    report("celsius_heat_index", scalar, batch);
+
+    let scalar = measure(|| {
+        for row in 0..ROWS {
+            output[row] = meteo_tools::celsius_to_fahrenheit(&temperatures[row]);
+        }
+        black_box(&output);
+    });
+    let batch = measure(|| {
+        batch::celsius_to_fahrenheit(&temperatures, &mut output);
+        black_box(&output);
+    });
+    report("celsius_to_fahrenheit", scalar, batch);
+
+    let scalar = measure(|| {
+        for row in 0..ROWS {
+            output[row] = meteo_tools::convert_pressure(
+                &pressures[row],
+                PressureUnit::Hectopascal,
+                PressureUnit::InchOfMercury,
+            );
+        }
+        black_box(&output);
+    });
+    let batch = measure(|| {
+        batch::convert_pressure(
+            &pressures,
+            PressureUnit::Hectopascal,
+            PressureUnit::InchOfMercury,
+            &mut output,
+        );
+        black_box(&output);
+    });
+    report("convert_pressure", scalar, batch);
+
+    let single: Vec<f32> = temperatures.iter().map(|value| *value as f32).collect();
+    let mut single_output = vec![0.0f32; ROWS];
+    let scalar = measure(|| {
+        for row in 0..ROWS {
+            single_output[row] = meteo_tools::celsius_to_fahrenheit(&single[row]);
+        }
+        black_box(&single_output);
+    });
+    let batch = measure(|| {
+        batch::celsius_to_fahrenheit(&single, &mut single_output);
+        black_box(&single_output);
+    });
+    report("celsius_to_fahrenheit f32", scalar, batch);
+}
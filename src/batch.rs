@@ -0,0 +1,275 @@
+//! Slice based variants of the humidity calculations, wind profiles and unit conversions for processing large datasets.
+//!
+//! Degree days and chill already take slices of daily or hourly temperatures, while evapotranspiration, solar position
+//! and radiation depend on date and location of each value and have no batch variants.
+//!
+//! Every function takes input slices and fills the output slice with results of the scalar function of the same name,
+//! so the results are identical to calling the scalar function row by row. Loops run over zipped slices without
+//! bounds checks. Simple conversions such as `celsius_to_fahrenheit` may be vectorised by the compiler, but calculations
+//! calling `exp`, `ln` or `powf`, such as the dew point or heat index, are not, and gain little
+//! over a scalar loop. Rounding to 4 decimal places vectorises only when the target has vector rounding instructions,
+//! e.g. with `-C target-cpu=native` on x86-64. Run `cargo bench` to measure the difference on a given target.
+//!
+//! # Panics
+//!
+//! All functions panic when the input and output slices have different lengths.
+//!
+//! # Examples
+//!
+//! ```
+//! let temperatures = [22.5, 25.0, 30.0];
+//! let relative_humidities = [62.4, 50.0, 40.0];
+//! let mut dew_points = [0.0; 3];
+//!
+//! meteo_tools::batch::common_celsius_dew_point(&temperatures, &relative_humidities, &mut dew_points);
+//!
+//! assert_eq!(dew_points[0], meteo_tools::common_celsius_dew_point(&22.5, &62.4));
+//! ```
+
+use crate::{
+    Float, LengthUnit, PrecipitationRateUnit, PressureUnit, RoughnessLength, SpeedUnit,
+    TemperatureScale,
+};
+
+macro_rules! batch_unary {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Batch variant of [`", stringify!($name), "`](crate::", stringify!($name), ").")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "Panics when the slices have different lengths."]
+            pub fn $name<F: Float>(values: &[F], output: &mut [F]) {
+                assert_eq!(values.len(), output.len(), "slices must have the same length");
+                for (value, result) in values.iter().zip(output.iter_mut()) {
+                    *result = crate::$name(value);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! batch_unit_conversion {
+    ($($name:ident: $unit:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Batch variant of [`", stringify!($name), "`](crate::", stringify!($name), ").")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "Panics when the slices have different lengths."]
+            pub fn $name<F: Float>(values: &[F], from: $unit, to: $unit, output: &mut [F]) {
+                assert_eq!(values.len(), output.len(), "slices must have the same length");
+                for (value, result) in values.iter().zip(output.iter_mut()) {
+                    *result = crate::$name(value, from, to);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! batch_humidity {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Batch variant of [`", stringify!($name), "`](crate::", stringify!($name), ").")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "Panics when the slices have different lengths."]
+            pub fn $name<F: Float>(temperatures: &[F], relative_humidities: &[F], output: &mut [F]) {
+                assert_eq!(temperatures.len(), output.len(), "slices must have the same length");
+                assert_eq!(relative_humidities.len(), output.len(), "slices must have the same length");
+                for ((temperature, relative_humidity), result) in temperatures
+                    .iter()
+                    .zip(relative_humidities)
+                    .zip(output.iter_mut())
+                {
+                    *result = crate::$name(temperature, relative_humidity);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! batch_humidity_pressure {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Batch variant of [`", stringify!($name), "`](crate::", stringify!($name), ").")]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "Panics when the slices have different lengths."]
+            pub fn $name<F: Float>(
+                temperatures: &[F],
+                relative_humidities: &[F],
+                atmospheric_pressures: &[F],
+                output: &mut [F],
+            ) {
+                assert_eq!(temperatures.len(), output.len(), "slices must have the same length");
+                assert_eq!(relative_humidities.len(), output.len(), "slices must have the same length");
+                assert_eq!(atmospheric_pressures.len(), output.len(), "slices must have the same length");
+                for (((temperature, relative_humidity), atmospheric_pressure), result) in temperatures
+                    .iter()
+                    .zip(relative_humidities)
+                    .zip(atmospheric_pressures)
+                    .zip(output.iter_mut())
+                {
+                    *result = crate::$name(temperature, relative_humidity, atmospheric_pressure);
+                }
+            }
+        )*
+    };
+}
+
+// Temperature
+batch_unary!(
+    celsius_to_fahrenheit,
+    fahrenheit_to_celsius,
+    celsius_to_kelvin,
+    kelvin_to_celsius,
+    fahrenheit_to_kelvin,
+    kelvin_to_fahrenheit,
+);
+
+// Pressure
+batch_unary!(hpa_to_mmhg, mmhg_to_hpa, hpa_to_inhg, inhg_to_hpa);
+
+// Speed
+batch_unary!(
+    mps_to_kmph,
+    kmph_to_mps,
+    kmph_to_mph,
+    mph_to_kmph,
+    mph_to_mps,
+    mps_to_mph,
+    mps_to_knots,
+    knots_to_mps,
+    kmph_to_knots,
+    knots_to_kmph,
+    knots_to_mph,
+    mph_to_knots,
+);
+
+// Any-to-any conversions
+batch_unit_conversion!(
+    convert_temperature: TemperatureScale,
+    convert_temperature_difference: TemperatureScale,
+    convert_pressure: PressureUnit,
+    convert_speed: SpeedUnit,
+    convert_length: LengthUnit,
+    convert_precipitation_rate: PrecipitationRateUnit,
+);
+
+// Calculations with common atmospheric pressure
+batch_humidity!(
+    common_celsius_dew_point,
+    common_fahrenheit_dew_point,
+    celsius_heat_index,
+    fahrenheit_heat_index,
+    common_celsius_humidex,
+    common_fahrenheit_humidex,
+    common_celsius_mixing_ratio,
+    common_fahrenheit_mixing_ratio,
+    celsius_absolute_humidity,
+    fahrenheit_absolute_humidity,
+);
+
+// Calculations with measured atmospheric pressure
+batch_humidity_pressure!(
+    celsius_dew_point,
+    fahrenheit_dew_point,
+    celsius_humidex,
+    fahrenheit_humidex,
+    celsius_mixing_ratio,
+    fahrenheit_mixing_ratio,
+);
+
+/// Batch variant of [`log_profile_wind_speed`](crate::log_profile_wind_speed) for speeds measured at the same height.
+///
+/// # Panics
+///
+/// Panics when `speeds` and `output` have different lengths.
+pub fn log_profile_wind_speed<F: Float>(
+    speeds: &[F],
+    measured_height: &F,
+    target_height: &F,
+    roughness: RoughnessLength,
+    unit: SpeedUnit,
+    output: &mut [F],
+) {
+    assert_eq!(
+        speeds.len(),
+        output.len(),
+        "slices must have the same length"
+    );
+    for (speed, result) in speeds.iter().zip(output.iter_mut()) {
+        *result =
+            crate::log_profile_wind_speed(speed, measured_height, target_height, roughness, unit);
+    }
+}
+
+/// Batch variant of [`power_law_wind_speed`](crate::power_law_wind_speed) for speeds measured at the same height.
+///
+/// # Panics
+///
+/// Panics when `speeds` and `output` have different lengths.
+pub fn power_law_wind_speed<F: Float>(
+    speeds: &[F],
+    measured_height: &F,
+    target_height: &F,
+    exponent: &F,
+    unit: SpeedUnit,
+    output: &mut [F],
+) {
+    assert_eq!(
+        speeds.len(),
+        output.len(),
+        "slices must have the same length"
+    );
+    for (speed, result) in speeds.iter().zip(output.iter_mut()) {
+        *result =
+            crate::power_law_wind_speed(speed, measured_height, target_height, exponent, unit);
+    }
+}
+
+/// Batch variant of [`common_power_law_wind_speed`](crate::common_power_law_wind_speed) for speeds measured at the same height.
+///
+/// # Panics
+///
+/// Panics when `speeds` and `output` have different lengths.
+pub fn common_power_law_wind_speed<F: Float>(
+    speeds: &[F],
+    measured_height: &F,
+    target_height: &F,
+    unit: SpeedUnit,
+    output: &mut [F],
+) {
+    assert_eq!(
+        speeds.len(),
+        output.len(),
+        "slices must have the same length"
+    );
+    for (speed, result) in speeds.iter().zip(output.iter_mut()) {
+        *result = crate::common_power_law_wind_speed(speed, measured_height, target_height, unit);
+    }
+}
+
+/// Batch variant of [`fao_wind_speed_at_2m`](crate::fao_wind_speed_at_2m) for speeds measured at the same height.
+///
+/// # Panics
+///
+/// Panics when `speeds` and `output` have different lengths.
+pub fn fao_wind_speed_at_2m<F: Float>(
+    speeds: &[F],
+    measured_height: &F,
+    unit: SpeedUnit,
+    output: &mut [F],
+) {
+    assert_eq!(
+        speeds.len(),
+        output.len(),
+        "slices must have the same length"
+    );
+    for (speed, result) in speeds.iter().zip(output.iter_mut()) {
+        *result = crate::fao_wind_speed_at_2m(speed, measured_height, unit);
+    }
+}
@@ -1,5 +1,11 @@
 pub mod absolute_humidity;
+pub mod chill;
+pub mod degree_days;
 pub mod dew_point;
+pub mod evapotranspiration;
 pub mod heat_index;
 pub mod humidex;
 pub mod mixing_ratio;
+pub mod radiation;
+pub mod solar;
+pub mod wind_profile;
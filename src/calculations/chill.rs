@@ -0,0 +1,144 @@
+use core::f64::consts::PI;
+
+use crate::constants::constants::{DYNAMIC_MODEL_COEFS, UTAH_CHILL_UNITS};
+use crate::{convert_temperature, meteo_round, Float, TemperatureScale};
+
+/// Counts chill hours, hours with temperature above 0 °C and at most 7.2 °C (32-45 °F).
+///
+/// Temperatures are hourly values in given scale.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// let temperatures = [-2.0, 1.0, 4.5, 7.2, 9.0, 6.0];
+/// let result = 4.0;
+///
+/// let chill_hours = meteo_tools::chill_hours(&temperatures, TemperatureScale::Celsius);
+///
+/// assert_eq!(chill_hours, result);
+/// ```
+pub fn chill_hours<F: Float>(hourly_temperatures: &[F], scale: TemperatureScale) -> F {
+    let mut hours = F::from_f64(0.0);
+    for temperature in hourly_temperatures {
+        let celsius = convert_temperature(temperature, scale, TemperatureScale::Celsius);
+        if celsius > F::from_f64(0.0) && celsius <= F::from_f64(7.2) {
+            hours += F::from_f64(1.0);
+        }
+    }
+    hours
+}
+
+/// Sums Utah chill units (Richardson et al., 1974) of hourly temperatures in given scale.
+///
+/// Hours between 2.5 and 9.1 °C count fully, cooler and slightly warmer hours count half,
+/// hours above 16 °C negate previously accumulated chill.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// let temperatures = [36.0, 40.0, 45.0, 50.0, 62.0, 70.0];
+/// let result = 1.5;
+///
+/// let chill_units = meteo_tools::utah_chill_units(&temperatures, TemperatureScale::Fahrenheit);
+///
+/// assert_eq!(chill_units, result);
+/// ```
+pub fn utah_chill_units<F: Float>(hourly_temperatures: &[F], scale: TemperatureScale) -> F {
+    let mut units = F::from_f64(0.0);
+    for temperature in hourly_temperatures {
+        let celsius = convert_temperature(temperature, scale, TemperatureScale::Celsius).to_f64();
+        let unit = UTAH_CHILL_UNITS
+            .iter()
+            .find(|(upper_bound, _)| celsius < *upper_bound)
+            .map_or(-1.0, |(_, unit)| *unit);
+        units += F::from_f64(unit);
+    }
+    units
+}
+
+/// Calculates chill portions of the Dynamic model (Fishman et al., 1987) from hourly temperatures in given scale.
+///
+/// Chill accumulates in an intermediate product, which is fixed as a portion once it reaches
+/// the critical level, or destroyed by warm hours before that. The series should start before
+/// the chill season, as the intermediate product starts at zero.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// let temperatures = [6.0; 240];
+/// let result = 7.8207;
+///
+/// let chill_portions = meteo_tools::chill_portions(&temperatures, TemperatureScale::Celsius);
+///
+/// assert_eq!(chill_portions, result);
+/// ```
+pub fn chill_portions<F: Float>(hourly_temperatures: &[F], scale: TemperatureScale) -> F {
+    let [e0, e1, a0, a1, slope, transition] = DYNAMIC_MODEL_COEFS;
+    let mut intermediate = 0.0;
+    let mut portions = 0.0;
+    for temperature in hourly_temperatures {
+        let kelvin =
+            convert_temperature(temperature, scale, TemperatureScale::Celsius).to_f64() + 273.0;
+        let exponent = Float::exp(slope * transition * (kelvin - transition) / kelvin);
+        let fixed_share = exponent / (1.0 + exponent);
+        let equilibrium = a0 / a1 * Float::exp((e1 - e0) / kelvin);
+        let rate = a1 * Float::exp(-e1 / kelvin);
+        intermediate = equilibrium - (equilibrium - intermediate) * Float::exp(-rate);
+        if intermediate >= 1.0 {
+            let portion = intermediate * fixed_share;
+            portions += portion;
+            intermediate -= portion;
+        }
+    }
+    meteo_round(&F::from_f64(portions))
+}
+
+/// Estimates hourly temperatures of a day from its minimum and maximum temperature.
+///
+/// Minimum is assumed at 06:00 and maximum at 14:00 local solar time, joined by cosine curves.
+/// The evening falls towards the next day's minimum, the night before 06:00 falls from the day's own maximum.
+/// Index of the returned array is the hour of the day.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// let hourly = meteo_tools::hourly_temperatures(&2.0, &12.0, &4.0);
+/// let chill_hours = meteo_tools::chill_hours(&hourly, TemperatureScale::Celsius);
+///
+/// assert_eq!(hourly[6], 2.0);
+/// assert_eq!(hourly[14], 12.0);
+/// assert_eq!(hourly[10], 7.0);
+/// assert_eq!(chill_hours, 11.0);
+/// ```
+pub fn hourly_temperatures<F: Float>(
+    min_temperature: &F,
+    max_temperature: &F,
+    next_min_temperature: &F,
+) -> [F; 24] {
+    let (min, max, next_min) = (*min_temperature, *max_temperature, *next_min_temperature);
+    let half = F::from_f64(0.5);
+    let mut temperatures = [min; 24];
+    for (hour, temperature) in temperatures.iter_mut().enumerate() {
+        let hour = hour as f64;
+        let value = if hour < 6.0 {
+            let phase = F::from_f64(PI * (hour + 10.0) / 16.0);
+            min + (max - min) * (F::from_f64(1.0) + phase.cos()) * half
+        } else if hour <= 14.0 {
+            let phase = F::from_f64(PI * (hour - 6.0) / 8.0);
+            min + (max - min) * (F::from_f64(1.0) - phase.cos()) * half
+        } else {
+            let phase = F::from_f64(PI * (hour - 14.0) / 16.0);
+            next_min + (max - next_min) * (F::from_f64(1.0) + phase.cos()) * half
+        };
+        *temperature = meteo_round(&value);
+    }
+    temperatures
+}
@@ -0,0 +1,376 @@
+use core::f64::consts::PI;
+
+use crate::{
+    convert_temperature, convert_temperature_difference, meteo_round, Float, TemperatureScale,
+};
+
+/// Method estimating the daily temperature curve from minimum and maximum temperature.
+///
+/// Double methods fit separate curves to the morning and afternoon, the afternoon one ending
+/// at the minimum temperature of the next day, which the variants carry in the scale of the daily temperatures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DegreeDayMethod {
+    /// Mean of minimum and maximum temperature.
+    Average,
+    /// Mean of minimum and maximum temperature, each first raised to the threshold,
+    /// e.g. the modified growing degree days of the US corn belt (base 50 °F, cap 86 °F).
+    ModifiedAverage,
+    /// Sine curve through minimum and maximum temperature (Baskerville-Emin).
+    SingleSine,
+    /// Sine curves through minimum, maximum and next day's minimum temperature.
+    DoubleSine(f64),
+    /// Triangle through minimum and maximum temperature.
+    SingleTriangle,
+    /// Triangles through minimum, maximum and next day's minimum temperature.
+    DoubleTriangle(f64),
+}
+
+/// Temperature threshold of degree days, in any temperature scale.
+///
+/// Thresholds are converted to the scale of the daily temperatures, so a base of 65 °F
+/// can be used with temperatures measured in Celsius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Threshold {
+    /// Threshold temperature in `scale`.
+    pub temperature: f64,
+    /// Temperature scale of the threshold.
+    pub scale: TemperatureScale,
+}
+
+impl Threshold {
+    /// Creates threshold from temperature in given scale.
+    pub fn new(temperature: f64, scale: TemperatureScale) -> Self {
+        Threshold { temperature, scale }
+    }
+
+    /// Creates threshold from temperature in Celsius.
+    pub fn celsius(temperature: f64) -> Self {
+        Threshold::new(temperature, TemperatureScale::Celsius)
+    }
+
+    /// Creates threshold from temperature in Fahrenheit.
+    pub fn fahrenheit(temperature: f64) -> Self {
+        Threshold::new(temperature, TemperatureScale::Fahrenheit)
+    }
+
+    fn celsius_value<F: Float>(&self) -> F {
+        convert_temperature(
+            &F::from_f64(self.temperature),
+            self.scale,
+            TemperatureScale::Celsius,
+        )
+    }
+}
+
+/// Calculates heating degree days of one day, the area below the base temperature.
+///
+/// Temperatures are in given scale, the result is in degree days of the same scale.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{DegreeDayMethod, TemperatureScale, Threshold};
+///
+/// let min_temperature = 2.0;
+/// let max_temperature = 10.0;
+/// let result = 12.3333;
+///
+/// let heating_degree_days = meteo_tools::heating_degree_days(&min_temperature, &max_temperature, TemperatureScale::Celsius, Threshold::fahrenheit(65.0), DegreeDayMethod::Average);
+///
+/// assert_eq!(heating_degree_days, result);
+/// ```
+pub fn heating_degree_days<F: Float>(
+    min_temperature: &F,
+    max_temperature: &F,
+    scale: TemperatureScale,
+    base: Threshold,
+    method: DegreeDayMethod,
+) -> F {
+    let day = DailyCurve::new(min_temperature, max_temperature, scale, method);
+    let base = base.celsius_value();
+    // Area below the base is the base minus the mean plus the area above the base
+    let degree_days = base - day.mean() + day.area_above(base);
+    day.to_scale(degree_days)
+}
+
+/// Calculates cooling degree days of one day, the area above the base temperature.
+///
+/// Temperatures are in given scale, the result is in degree days of the same scale.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{DegreeDayMethod, TemperatureScale, Threshold};
+///
+/// let min_temperature = 18.0;
+/// let max_temperature = 30.0;
+/// let result = 3.017;
+///
+/// let cooling_degree_days = meteo_tools::cooling_degree_days(&min_temperature, &max_temperature, TemperatureScale::Celsius, Threshold::celsius(22.0), DegreeDayMethod::SingleSine);
+///
+/// assert_eq!(cooling_degree_days, result);
+/// ```
+pub fn cooling_degree_days<F: Float>(
+    min_temperature: &F,
+    max_temperature: &F,
+    scale: TemperatureScale,
+    base: Threshold,
+    method: DegreeDayMethod,
+) -> F {
+    let day = DailyCurve::new(min_temperature, max_temperature, scale, method);
+    let degree_days = day.area_above(base.celsius_value());
+    day.to_scale(degree_days)
+}
+
+/// Calculates growing degree days of one day, the area between the lower and optional upper threshold.
+///
+/// Temperatures above the upper threshold are cut off horizontally.
+/// Temperatures are in given scale, the result is in degree days of the same scale.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{DegreeDayMethod, TemperatureScale, Threshold};
+///
+/// let min_temperature = 60.0;
+/// let max_temperature = 95.0;
+/// let result = 23.0;
+///
+/// let growing_degree_days = meteo_tools::growing_degree_days(&min_temperature, &max_temperature, TemperatureScale::Fahrenheit, Threshold::fahrenheit(50.0), Some(Threshold::fahrenheit(86.0)), DegreeDayMethod::ModifiedAverage);
+///
+/// assert_eq!(growing_degree_days, result);
+/// ```
+pub fn growing_degree_days<F: Float>(
+    min_temperature: &F,
+    max_temperature: &F,
+    scale: TemperatureScale,
+    lower: Threshold,
+    upper: Option<Threshold>,
+    method: DegreeDayMethod,
+) -> F {
+    let day = DailyCurve::new(min_temperature, max_temperature, scale, method);
+    let mut degree_days = day.area_above(lower.celsius_value());
+    if let Some(upper) = upper {
+        degree_days = degree_days - day.area_above(upper.celsius_value());
+    }
+    day.to_scale(degree_days)
+}
+
+/// Sums heating degree days of consecutive days.
+///
+/// Double methods use the minimum temperature of the following day, the value carried by the method
+/// is used for the last day.
+///
+/// # Panics
+///
+/// Panics when `min_temperatures` and `max_temperatures` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{DegreeDayMethod, TemperatureScale, Threshold};
+///
+/// let min_temperatures = [2.0, 4.0, -1.0];
+/// let max_temperatures = [10.0, 12.0, 6.0];
+/// let result = 37.5;
+///
+/// let heating_degree_days = meteo_tools::accumulated_heating_degree_days(&min_temperatures, &max_temperatures, TemperatureScale::Celsius, Threshold::celsius(18.0), DegreeDayMethod::Average);
+///
+/// assert_eq!(heating_degree_days, result);
+/// ```
+pub fn accumulated_heating_degree_days<F: Float>(
+    min_temperatures: &[F],
+    max_temperatures: &[F],
+    scale: TemperatureScale,
+    base: Threshold,
+    method: DegreeDayMethod,
+) -> F {
+    accumulate(
+        min_temperatures,
+        max_temperatures,
+        method,
+        |min, max, method| heating_degree_days(min, max, scale, base, method),
+    )
+}
+
+/// Sums cooling degree days of consecutive days, see [`accumulated_heating_degree_days`].
+///
+/// # Panics
+///
+/// Panics when `min_temperatures` and `max_temperatures` have different lengths.
+pub fn accumulated_cooling_degree_days<F: Float>(
+    min_temperatures: &[F],
+    max_temperatures: &[F],
+    scale: TemperatureScale,
+    base: Threshold,
+    method: DegreeDayMethod,
+) -> F {
+    accumulate(
+        min_temperatures,
+        max_temperatures,
+        method,
+        |min, max, method| cooling_degree_days(min, max, scale, base, method),
+    )
+}
+
+/// Sums growing degree days of consecutive days, see [`accumulated_heating_degree_days`].
+///
+/// # Panics
+///
+/// Panics when `min_temperatures` and `max_temperatures` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{DegreeDayMethod, TemperatureScale, Threshold};
+///
+/// let min_temperatures = [8.0, 12.0, 14.0];
+/// let max_temperatures = [20.0, 26.0, 31.0];
+/// let result = 26.8242;
+///
+/// let growing_degree_days = meteo_tools::accumulated_growing_degree_days(&min_temperatures, &max_temperatures, TemperatureScale::Celsius, Threshold::celsius(10.0), Some(Threshold::celsius(30.0)), DegreeDayMethod::DoubleSine(13.0));
+///
+/// assert_eq!(growing_degree_days, result);
+/// ```
+pub fn accumulated_growing_degree_days<F: Float>(
+    min_temperatures: &[F],
+    max_temperatures: &[F],
+    scale: TemperatureScale,
+    lower: Threshold,
+    upper: Option<Threshold>,
+    method: DegreeDayMethod,
+) -> F {
+    accumulate(
+        min_temperatures,
+        max_temperatures,
+        method,
+        |min, max, method| growing_degree_days(min, max, scale, lower, upper, method),
+    )
+}
+
+// Sums daily degree days, passing the next day's minimum temperature to double methods,
+// panics when the slices have different lengths
+fn accumulate<F: Float>(
+    min_temperatures: &[F],
+    max_temperatures: &[F],
+    method: DegreeDayMethod,
+    daily: impl Fn(&F, &F, DegreeDayMethod) -> F,
+) -> F {
+    assert_eq!(
+        min_temperatures.len(),
+        max_temperatures.len(),
+        "slices must have the same length"
+    );
+    let mut total = F::from_f64(0.0);
+    for (day, (min, max)) in min_temperatures.iter().zip(max_temperatures).enumerate() {
+        let next_min = min_temperatures.get(day + 1).map(|min| min.to_f64());
+        let method = match (method, next_min) {
+            (DegreeDayMethod::DoubleSine(_), Some(next_min)) => {
+                DegreeDayMethod::DoubleSine(next_min)
+            }
+            (DegreeDayMethod::DoubleTriangle(_), Some(next_min)) => {
+                DegreeDayMethod::DoubleTriangle(next_min)
+            }
+            (method, _) => method,
+        };
+        total += daily(min, max, method);
+    }
+    meteo_round(&total)
+}
+
+// Daily temperatures converted to Celsius
+struct DailyCurve<F> {
+    min: F,
+    max: F,
+    next_min: F,
+    scale: TemperatureScale,
+    method: DegreeDayMethod,
+}
+
+impl<F: Float> DailyCurve<F> {
+    fn new(min: &F, max: &F, scale: TemperatureScale, method: DegreeDayMethod) -> Self {
+        let next_min = match method {
+            DegreeDayMethod::DoubleSine(next_min) | DegreeDayMethod::DoubleTriangle(next_min) => {
+                convert_temperature(&F::from_f64(next_min), scale, TemperatureScale::Celsius)
+            }
+            _ => convert_temperature(min, scale, TemperatureScale::Celsius),
+        };
+        DailyCurve {
+            min: convert_temperature(min, scale, TemperatureScale::Celsius),
+            max: convert_temperature(max, scale, TemperatureScale::Celsius),
+            next_min,
+            scale,
+            method,
+        }
+    }
+
+    fn mean(&self) -> F {
+        let half = F::from_f64(0.5);
+        match self.method {
+            DegreeDayMethod::DoubleSine(_) | DegreeDayMethod::DoubleTriangle(_) => {
+                (self.min + self.next_min) * half * half + self.max * half
+            }
+            _ => (self.min + self.max) * half,
+        }
+    }
+
+    // Area of the temperature curve above the threshold in degree days
+    fn area_above(&self, threshold: F) -> F {
+        let half = F::from_f64(0.5);
+        match self.method {
+            DegreeDayMethod::Average => (self.mean() - threshold).max(F::from_f64(0.0)),
+            DegreeDayMethod::ModifiedAverage => {
+                (self.min.max(threshold) + self.max.max(threshold)) * half - threshold
+            }
+            DegreeDayMethod::SingleSine => sine_area_above(self.min, self.max, threshold),
+            DegreeDayMethod::DoubleSine(_) => {
+                (sine_area_above(self.min, self.max, threshold)
+                    + sine_area_above(self.next_min, self.max, threshold))
+                    * half
+            }
+            DegreeDayMethod::SingleTriangle => triangle_area_above(self.min, self.max, threshold),
+            DegreeDayMethod::DoubleTriangle(_) => {
+                (triangle_area_above(self.min, self.max, threshold)
+                    + triangle_area_above(self.next_min, self.max, threshold))
+                    * half
+            }
+        }
+    }
+
+    // Converts degree days in Celsius to the scale of the daily temperatures
+    fn to_scale(&self, degree_days: F) -> F {
+        let degree_days =
+            convert_temperature_difference(&degree_days, TemperatureScale::Celsius, self.scale);
+        meteo_round(&degree_days.abs())
+    }
+}
+
+// Area above the threshold of a sine curve between minimum and maximum temperature over one day
+fn sine_area_above<F: Float>(min: F, max: F, threshold: F) -> F {
+    let mean = (min + max) * F::from_f64(0.5);
+    let amplitude = (max - min) * F::from_f64(0.5);
+    if threshold >= max {
+        F::from_f64(0.0)
+    } else if threshold <= min {
+        mean - threshold
+    } else {
+        let sine = (threshold - mean) / amplitude;
+        let angle = sine.asin();
+        let cosine = (F::from_f64(1.0) - sine * sine).sqrt();
+        ((mean - threshold) * (F::from_f64(PI / 2.0) - angle) + amplitude * cosine)
+            / F::from_f64(PI)
+    }
+}
+
+// Area above the threshold of a triangle between minimum and maximum temperature over one day
+fn triangle_area_above<F: Float>(min: F, max: F, threshold: F) -> F {
+    if threshold >= max {
+        F::from_f64(0.0)
+    } else if threshold <= min {
+        (min + max) * F::from_f64(0.5) - threshold
+    } else {
+        (max - threshold) * (max - threshold) / (F::from_f64(2.0) * (max - min))
+    }
+}
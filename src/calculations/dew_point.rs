@@ -1,7 +1,8 @@
 use crate::constants::constants::{
-    AVG_ATMOSPHERIC_PRESSURE, LATENT_HEAT_OF_VAPORIZATION, STANDARD_CONDENSATION_POINT,
+    AVG_ATMOSPHERIC_PRESSURE, BAROMETRIC_EXPONENT, LATENT_HEAT_OF_VAPORIZATION,
+    STANDARD_CONDENSATION_POINT,
 };
-use crate::{celsius_to_fahrenheit, fahrenheit_to_celsius, meteo_round};
+use crate::{celsius_to_fahrenheit, fahrenheit_to_celsius, meteo_round, Float};
 
 /// Calculates dew point using Magnus-Tetens formula using Celsius with common atmospheric pressure using constant.
 ///
@@ -18,11 +19,12 @@ use crate::{celsius_to_fahrenheit, fahrenheit_to_celsius, meteo_round};
 ///
 /// assert_eq!(dew_point, result);
 /// ```
-pub fn common_celsius_dew_point(temperature: &f64, relative_humidity: &f64) -> f64 {
-    let gamma = ((LATENT_HEAT_OF_VAPORIZATION * temperature)
-        / (STANDARD_CONDENSATION_POINT + temperature))
-        + (relative_humidity / 100.0).ln();
-    let dew_point = STANDARD_CONDENSATION_POINT * gamma / (LATENT_HEAT_OF_VAPORIZATION - gamma);
+pub fn common_celsius_dew_point<F: Float>(temperature: &F, relative_humidity: &F) -> F {
+    let latent_heat = F::from_f64(LATENT_HEAT_OF_VAPORIZATION);
+    let condensation_point = F::from_f64(STANDARD_CONDENSATION_POINT);
+    let gamma = ((latent_heat * *temperature) / (condensation_point + *temperature))
+        + (*relative_humidity / F::from_f64(100.0)).ln();
+    let dew_point = condensation_point * gamma / (latent_heat - gamma);
     meteo_round(&dew_point)
 }
 
@@ -43,13 +45,13 @@ pub fn common_celsius_dew_point(temperature: &f64, relative_humidity: &f64) -> f
 ///
 /// assert_eq!(dew_point, result);
 /// ```
-pub fn celsius_dew_point(
-    temperature: &f64,
-    relative_humidity: &f64,
-    atmospheric_pressure: &f64,
-) -> f64 {
-    let dew_point = common_celsius_dew_point(&temperature, &relative_humidity);
-    calculate_exact_pressure_offset(&atmospheric_pressure, &dew_point)
+pub fn celsius_dew_point<F: Float>(
+    temperature: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
+    let dew_point = common_celsius_dew_point(temperature, relative_humidity);
+    calculate_exact_pressure_offset(atmospheric_pressure, &dew_point)
 }
 
 /// Calculates common dew point using Magnus-Tetens formula using Fahrenheit with common atmospheric pressure using constant.
@@ -67,7 +69,7 @@ pub fn celsius_dew_point(
 ///
 /// assert_eq!(dew_point, result);
 /// ```
-pub fn common_fahrenheit_dew_point(temperature: &f64, relative_humidity: &f64) -> f64 {
+pub fn common_fahrenheit_dew_point<F: Float>(temperature: &F, relative_humidity: &F) -> F {
     let temperature = fahrenheit_to_celsius(temperature);
     let dew_point =
         celsius_to_fahrenheit(&common_celsius_dew_point(&temperature, relative_humidity));
@@ -91,25 +93,29 @@ pub fn common_fahrenheit_dew_point(temperature: &f64, relative_humidity: &f64) -
 ///
 /// assert_eq!(dew_point, result);
 /// ```
-pub fn fahrenheit_dew_point(
-    temperature: &f64,
-    relative_humidity: &f64,
-    atmospheric_pressure: &f64,
-) -> f64 {
-    let dew_point = common_fahrenheit_dew_point(&temperature, &relative_humidity);
-    calculate_exact_pressure_offset(&atmospheric_pressure, &dew_point)
+pub fn fahrenheit_dew_point<F: Float>(
+    temperature: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
+    let dew_point = common_fahrenheit_dew_point(temperature, relative_humidity);
+    calculate_exact_pressure_offset(atmospheric_pressure, &dew_point)
 }
 
 // Calculates offset to correct when exact atmospheric pressure measurement given
-fn calculate_exact_pressure_offset(atmospheric_pressure: &f64, dew_point: &f64) -> f64 {
-    if *atmospheric_pressure != AVG_ATMOSPHERIC_PRESSURE {
-        let dew_point_full = dew_point
-            / (1.0
-                - (atmospheric_pressure - AVG_ATMOSPHERIC_PRESSURE) / AVG_ATMOSPHERIC_PRESSURE
-                    * 0.190284)
-                .abs();
+pub(crate) fn calculate_exact_pressure_offset<F: Float>(
+    atmospheric_pressure: &F,
+    dew_point: &F,
+) -> F {
+    let average_pressure = F::from_f64(AVG_ATMOSPHERIC_PRESSURE);
+    if *atmospheric_pressure != average_pressure {
+        let dew_point_full = *dew_point
+            / (F::from_f64(1.0)
+                - (*atmospheric_pressure - average_pressure) / average_pressure
+                    * F::from_f64(BAROMETRIC_EXPONENT))
+            .abs();
         meteo_round(&dew_point_full)
     } else {
-        meteo_round(&dew_point)
+        meteo_round(dew_point)
     }
 }
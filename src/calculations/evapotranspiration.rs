@@ -0,0 +1,484 @@
+use crate::calculations::radiation::{
+    unrounded_clear_sky_radiation, unrounded_extraterrestrial_radiation,
+};
+use crate::constants::constants::{
+    FAO_SATURATION_VAPOR_PRESSURE_COEFS, GRASS_ALBEDO, HARGREAVES_COEFS,
+    HARGREAVES_RADIATION_COEFFICIENT, KELVIN_DEGREE_CONSTANT, LATENT_HEAT_OF_VAPORIZATION_MJ,
+    PRIESTLEY_TAYLOR_ALPHA, STEFAN_BOLTZMANN_DAILY,
+};
+use crate::{fao_wind_speed_at_2m, meteo_round, Float, SpeedUnit};
+
+/// Daily weather of a station for FAO-56 reference evapotranspiration.
+///
+/// Humidity is taken from the first available of dew point, minimum and maximum relative humidity
+/// or mean relative humidity. Without any, dew point is assumed equal to minimum temperature.
+/// Missing solar radiation is estimated from the temperature range (Hargreaves),
+/// missing wind speed defaults to 2 m/s as recommended by FAO-56.
+/// Evapotranspiration is not calculated when maximum temperature is below minimum temperature.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::DailyWeather;
+///
+/// // FAO-56 example 18, Brussels on 6 July
+/// let weather = DailyWeather::new(12.3, 21.5, 50.8, 100.0, 187)
+///     .with_relative_humidity(63.0, 84.0)
+///     .with_wind(2.78, 10.0)
+///     .with_solar_radiation(22.07);
+/// let result = 3.881;
+///
+/// let evapotranspiration = weather.reference_evapotranspiration();
+///
+/// assert_eq!(evapotranspiration, Some(result));
+/// assert_eq!(DailyWeather::new(21.5, 12.3, 50.8, 100.0, 187).reference_evapotranspiration(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct DailyWeather {
+    /// Minimum air temperature in degrees Celsius.
+    pub min_temperature: f64,
+    /// Maximum air temperature in degrees Celsius.
+    pub max_temperature: f64,
+    /// Latitude in degrees, negative on the southern hemisphere.
+    pub latitude: f64,
+    /// Elevation above sea level in meters.
+    pub elevation: f64,
+    /// Day of the year, 1 for 1 January.
+    pub day_of_year: u16,
+    /// Dew point in degrees Celsius.
+    pub dew_point: Option<f64>,
+    /// Minimum and maximum relative humidity in percent.
+    pub relative_humidity_range: Option<(f64, f64)>,
+    /// Mean relative humidity in percent.
+    pub mean_relative_humidity: Option<f64>,
+    /// Wind speed in m/s.
+    pub wind_speed: Option<f64>,
+    /// Height of the wind speed measurement in meters.
+    pub wind_height: f64,
+    /// Incoming solar radiation in MJ/m² per day.
+    pub solar_radiation: Option<f64>,
+}
+
+impl DailyWeather {
+    /// Creates daily weather from temperatures in degrees Celsius and the location of the station.
+    pub fn new(
+        min_temperature: f64,
+        max_temperature: f64,
+        latitude: f64,
+        elevation: f64,
+        day_of_year: u16,
+    ) -> Self {
+        DailyWeather {
+            min_temperature,
+            max_temperature,
+            latitude,
+            elevation,
+            day_of_year,
+            dew_point: None,
+            relative_humidity_range: None,
+            mean_relative_humidity: None,
+            wind_speed: None,
+            wind_height: 2.0,
+            solar_radiation: None,
+        }
+    }
+
+    /// Sets dew point in degrees Celsius.
+    pub fn with_dew_point(mut self, dew_point: f64) -> Self {
+        self.dew_point = Some(dew_point);
+        self
+    }
+
+    /// Sets minimum and maximum relative humidity in percent.
+    pub fn with_relative_humidity(mut self, min: f64, max: f64) -> Self {
+        self.relative_humidity_range = Some((min, max));
+        self
+    }
+
+    /// Sets mean relative humidity in percent.
+    pub fn with_mean_relative_humidity(mut self, relative_humidity: f64) -> Self {
+        self.mean_relative_humidity = Some(relative_humidity);
+        self
+    }
+
+    /// Sets wind speed in m/s measured at given height in meters.
+    pub fn with_wind(mut self, speed: f64, height: f64) -> Self {
+        self.wind_speed = Some(speed);
+        self.wind_height = height;
+        self
+    }
+
+    /// Sets incoming solar radiation in MJ/m² per day.
+    pub fn with_solar_radiation(mut self, radiation: f64) -> Self {
+        self.solar_radiation = Some(radiation);
+        self
+    }
+
+    /// Calculates grass reference evapotranspiration in mm per day using FAO-56 Penman-Monteith equation.
+    ///
+    /// Returns `None` when maximum temperature is below minimum temperature or either of them is NaN.
+    pub fn reference_evapotranspiration(&self) -> Option<f64> {
+        let (min, max) = (self.min_temperature, self.max_temperature);
+        if min.is_nan() || max.is_nan() || max < min {
+            return None;
+        }
+        let mean_temperature = (min + max) / 2.0;
+        let saturation_vapor_pressure =
+            (fao_saturation_vapor_pressure(&min) + fao_saturation_vapor_pressure(&max)) / 2.0;
+        let actual_vapor_pressure = if let Some(dew_point) = self.dew_point {
+            fao_saturation_vapor_pressure(&dew_point)
+        } else if let Some((min_humidity, max_humidity)) = self.relative_humidity_range {
+            (fao_saturation_vapor_pressure(&min) * max_humidity
+                + fao_saturation_vapor_pressure(&max) * min_humidity)
+                / 200.0
+        } else if let Some(humidity) = self.mean_relative_humidity {
+            saturation_vapor_pressure * humidity / 100.0
+        } else {
+            fao_saturation_vapor_pressure(&min)
+        };
+
+        let extraterrestrial =
+            unrounded_extraterrestrial_radiation(&self.latitude, self.day_of_year);
+        let solar_radiation = self.solar_radiation.unwrap_or_else(|| {
+            HARGREAVES_RADIATION_COEFFICIENT * Float::sqrt(max - min) * extraterrestrial
+        });
+        let clear_sky_radiation = unrounded_clear_sky_radiation(&extraterrestrial, &self.elevation);
+        let net_radiation = fao_net_radiation(
+            &solar_radiation,
+            &clear_sky_radiation,
+            &min,
+            &max,
+            &actual_vapor_pressure,
+        );
+
+        let wind_speed = match self.wind_speed {
+            Some(speed) => {
+                fao_wind_speed_at_2m(&speed, &self.wind_height, SpeedUnit::MetersPerSecond)
+            }
+            None => 2.0,
+        };
+
+        Some(fao_reference_evapotranspiration(
+            &net_radiation,
+            &mean_temperature,
+            &wind_speed,
+            &saturation_vapor_pressure,
+            &actual_vapor_pressure,
+            &pressure_at_elevation(&self.elevation),
+        ))
+    }
+}
+
+/// Calculates saturation vapor pressure over water using FAO-56 equation 11.
+///
+/// Takes temperature in Celsius, returns pressure in hPa
+///
+/// FAO-56 fits the Magnus formula with 6.108 hPa and 237.3 °C, while the humidity functions of the crate
+/// use 6.112 hPa and 237.7 °C. The results differ by less than 0.3 % between 0 and 40 °C, FAO-56
+/// coefficients are kept here so that evapotranspiration matches the FAO-56 tables and examples.
+///
+/// # Examples
+///
+/// ```
+/// let temperature = 20.0;
+/// let result = 23.3828;
+///
+/// let saturation_vapor_pressure = meteo_tools::fao_saturation_vapor_pressure(&temperature);
+///
+/// assert_eq!(saturation_vapor_pressure, result);
+/// ```
+pub fn fao_saturation_vapor_pressure<F: Float>(temperature: &F) -> F {
+    meteo_round(&unrounded_saturation_vapor_pressure(temperature))
+}
+
+/// Calculates slope of the saturation vapor pressure curve using FAO-56 equation 13.
+///
+/// Takes temperature in Celsius, returns slope in hPa/°C
+///
+/// # Examples
+///
+/// ```
+/// let temperature = 20.0;
+/// let result = 1.4474;
+///
+/// let slope = meteo_tools::saturation_vapor_pressure_slope(&temperature);
+///
+/// assert_eq!(slope, result);
+/// ```
+pub fn saturation_vapor_pressure_slope<F: Float>(temperature: &F) -> F {
+    meteo_round(&unrounded_saturation_vapor_pressure_slope(temperature))
+}
+
+/// Calculates psychrometric constant using FAO-56 equation 8.
+///
+/// Takes atmospheric pressure in hPa, returns constant in hPa/°C
+///
+/// # Examples
+///
+/// ```
+/// let pressure = 1013.25;
+/// let result = 0.6738;
+///
+/// let psychrometric_constant = meteo_tools::psychrometric_constant(&pressure);
+///
+/// assert_eq!(psychrometric_constant, result);
+/// ```
+pub fn psychrometric_constant<F: Float>(atmospheric_pressure: &F) -> F {
+    meteo_round(&unrounded_psychrometric_constant(atmospheric_pressure))
+}
+
+/// Calculates atmospheric pressure of the standard atmosphere at given elevation using FAO-56 equation 7.
+///
+/// Takes elevation in meters, returns pressure in hPa
+///
+/// # Examples
+///
+/// ```
+/// let elevation = 1800.0;
+/// let result = 817.558;
+///
+/// let pressure = meteo_tools::pressure_at_elevation(&elevation);
+///
+/// assert_eq!(pressure, result);
+/// ```
+pub fn pressure_at_elevation<F: Float>(elevation: &F) -> F {
+    let ratio = (F::from_f64(293.0) - F::from_f64(0.0065) * *elevation) / F::from_f64(293.0);
+    meteo_round(&(F::from_f64(1013.0) * ratio.powf(F::from_f64(5.26))))
+}
+
+/// Calculates daily net radiation of the grass reference surface using FAO-56 equations 38-40.
+///
+/// Takes solar and clear-sky radiation in MJ/m² per day, temperatures in Celsius and actual vapor pressure in hPa,
+/// returns net radiation in MJ/m² per day
+///
+/// # Examples
+///
+/// ```
+/// let solar_radiation = 14.5;
+/// let clear_sky_radiation = 18.8;
+/// let min_temperature = 19.1;
+/// let max_temperature = 25.1;
+/// let actual_vapor_pressure = 21.0;
+/// let result = 7.6314;
+///
+/// let net_radiation = meteo_tools::fao_net_radiation(&solar_radiation, &clear_sky_radiation, &min_temperature, &max_temperature, &actual_vapor_pressure);
+///
+/// assert_eq!(net_radiation, result);
+/// ```
+pub fn fao_net_radiation<F: Float>(
+    solar_radiation: &F,
+    clear_sky_radiation: &F,
+    min_temperature: &F,
+    max_temperature: &F,
+    actual_vapor_pressure: &F,
+) -> F {
+    let kelvin = F::from_f64(KELVIN_DEGREE_CONSTANT);
+    let net_shortwave = (F::from_f64(1.0) - F::from_f64(GRASS_ALBEDO)) * *solar_radiation;
+    let relative_shortwave = (*solar_radiation / *clear_sky_radiation).min(F::from_f64(1.0));
+    let net_longwave = F::from_f64(STEFAN_BOLTZMANN_DAILY)
+        * ((*max_temperature + kelvin).powi(4) + (*min_temperature + kelvin).powi(4))
+        / F::from_f64(2.0)
+        * (F::from_f64(0.34)
+            - F::from_f64(0.14) * (*actual_vapor_pressure / F::from_f64(10.0)).sqrt())
+        * (F::from_f64(1.35) * relative_shortwave - F::from_f64(0.35));
+    meteo_round(&(net_shortwave - net_longwave))
+}
+
+/// Calculates daily grass reference evapotranspiration using FAO-56 Penman-Monteith equation 6.
+///
+/// Takes net radiation in MJ/m² per day, mean temperature in Celsius, wind speed at 2 m in m/s,
+/// saturation and actual vapor pressure and atmospheric pressure in hPa, returns evapotranspiration in mm per day.
+/// Soil heat flux is neglected for daily periods.
+///
+/// # Examples
+///
+/// ```
+/// let net_radiation = 13.28;
+/// let mean_temperature = 16.9;
+/// let wind_speed = 2.078;
+/// let saturation_vapor_pressure = 19.97;
+/// let actual_vapor_pressure = 14.09;
+/// let pressure = 1001.0;
+/// let result = 3.8788;
+///
+/// let evapotranspiration = meteo_tools::fao_reference_evapotranspiration(&net_radiation, &mean_temperature, &wind_speed, &saturation_vapor_pressure, &actual_vapor_pressure, &pressure);
+///
+/// assert_eq!(evapotranspiration, result);
+/// ```
+pub fn fao_reference_evapotranspiration<F: Float>(
+    net_radiation: &F,
+    mean_temperature: &F,
+    wind_speed: &F,
+    saturation_vapor_pressure: &F,
+    actual_vapor_pressure: &F,
+    atmospheric_pressure: &F,
+) -> F {
+    let evapotranspiration = penman_monteith(
+        *net_radiation,
+        F::from_f64(0.0),
+        *mean_temperature,
+        *wind_speed,
+        *saturation_vapor_pressure - *actual_vapor_pressure,
+        *atmospheric_pressure,
+        F::from_f64(900.0),
+    );
+    meteo_round(&evapotranspiration)
+}
+
+/// Calculates hourly grass reference evapotranspiration using FAO-56 Penman-Monteith equation 53.
+///
+/// Takes net radiation in MJ/m² per hour, temperature in Celsius, wind speed at 2 m in m/s,
+/// relative humidity in percent and atmospheric pressure in hPa, returns evapotranspiration in mm per hour.
+/// Soil heat flux is estimated as 10 % of positive (daytime) and 50 % of negative (nighttime) net radiation.
+///
+/// # Examples
+///
+/// ```
+/// // FAO-56 example 19, N'Diaye between 14 and 15 hours
+/// let net_radiation = 1.749;
+/// let temperature = 38.0;
+/// let wind_speed = 3.3;
+/// let relative_humidity = 52.0;
+/// let pressure = 1013.25;
+/// let result = 0.627;
+///
+/// let evapotranspiration = meteo_tools::fao_hourly_reference_evapotranspiration(&net_radiation, &temperature, &wind_speed, &relative_humidity, &pressure);
+///
+/// assert_eq!(evapotranspiration, result);
+/// ```
+pub fn fao_hourly_reference_evapotranspiration<F: Float>(
+    net_radiation: &F,
+    temperature: &F,
+    wind_speed: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
+    let soil_heat_flux = if *net_radiation > F::from_f64(0.0) {
+        F::from_f64(0.1) * *net_radiation
+    } else {
+        F::from_f64(0.5) * *net_radiation
+    };
+    let saturation_vapor_pressure = unrounded_saturation_vapor_pressure(temperature);
+    let vapor_pressure_deficit =
+        saturation_vapor_pressure * (F::from_f64(1.0) - *relative_humidity / F::from_f64(100.0));
+    let evapotranspiration = penman_monteith(
+        *net_radiation,
+        soil_heat_flux,
+        *temperature,
+        *wind_speed,
+        vapor_pressure_deficit,
+        *atmospheric_pressure,
+        F::from_f64(37.0),
+    );
+    meteo_round(&evapotranspiration)
+}
+
+/// Estimates daily reference evapotranspiration from temperatures only using Hargreaves-Samani equation (FAO-56 equation 52).
+///
+/// Takes temperatures in Celsius, latitude in degrees and day of the year, returns evapotranspiration in mm per day
+///
+/// # Examples
+///
+/// ```
+/// let min_temperature = 14.8;
+/// let max_temperature = 26.6;
+/// let latitude = 45.0;
+/// let day_of_year = 196;
+/// let result = 5.0406;
+///
+/// let evapotranspiration = meteo_tools::hargreaves_evapotranspiration(&min_temperature, &max_temperature, &latitude, day_of_year);
+///
+/// assert_eq!(evapotranspiration, result);
+/// ```
+pub fn hargreaves_evapotranspiration<F: Float>(
+    min_temperature: &F,
+    max_temperature: &F,
+    latitude: &F,
+    day_of_year: u16,
+) -> F {
+    let coefs = HARGREAVES_COEFS.map(F::from_f64);
+    let mean_temperature = (*min_temperature + *max_temperature) / F::from_f64(2.0);
+    let radiation = unrounded_extraterrestrial_radiation(latitude, day_of_year)
+        / F::from_f64(LATENT_HEAT_OF_VAPORIZATION_MJ);
+    let evapotranspiration = coefs[0]
+        * (mean_temperature + coefs[1])
+        * (*max_temperature - *min_temperature)
+            .max(F::from_f64(0.0))
+            .sqrt()
+        * radiation;
+    meteo_round(&evapotranspiration)
+}
+
+/// Estimates daily evapotranspiration of a well watered surface from radiation using Priestley-Taylor equation.
+///
+/// Takes net radiation in MJ/m² per day, mean temperature in Celsius and atmospheric pressure in hPa,
+/// returns evapotranspiration in mm per day
+///
+/// # Examples
+///
+/// ```
+/// let net_radiation = 13.28;
+/// let mean_temperature = 16.9;
+/// let pressure = 1001.0;
+/// let result = 4.4202;
+///
+/// let evapotranspiration = meteo_tools::priestley_taylor_evapotranspiration(&net_radiation, &mean_temperature, &pressure);
+///
+/// assert_eq!(evapotranspiration, result);
+/// ```
+pub fn priestley_taylor_evapotranspiration<F: Float>(
+    net_radiation: &F,
+    mean_temperature: &F,
+    atmospheric_pressure: &F,
+) -> F {
+    let slope = unrounded_saturation_vapor_pressure_slope(mean_temperature);
+    let psychrometric_constant = unrounded_psychrometric_constant(atmospheric_pressure);
+    let evapotranspiration = F::from_f64(PRIESTLEY_TAYLOR_ALPHA) * slope
+        / (slope + psychrometric_constant)
+        * *net_radiation
+        / F::from_f64(LATENT_HEAT_OF_VAPORIZATION_MJ);
+    meteo_round(&evapotranspiration)
+}
+
+// Penman-Monteith equation with pressures in hPa, the coefficient is 900 for daily and 37 for hourly periods
+fn penman_monteith<F: Float>(
+    net_radiation: F,
+    soil_heat_flux: F,
+    temperature: F,
+    wind_speed: F,
+    vapor_pressure_deficit: F,
+    atmospheric_pressure: F,
+    coefficient: F,
+) -> F {
+    let kilopascals = F::from_f64(10.0);
+    let slope = unrounded_saturation_vapor_pressure_slope(&temperature) / kilopascals;
+    let psychrometric_constant =
+        unrounded_psychrometric_constant(&atmospheric_pressure) / kilopascals;
+    let radiation_term = F::from_f64(1.0 / LATENT_HEAT_OF_VAPORIZATION_MJ)
+        * slope
+        * (net_radiation - soil_heat_flux);
+    let aerodynamic_term = psychrometric_constant * coefficient
+        / (temperature + F::from_f64(KELVIN_DEGREE_CONSTANT))
+        * wind_speed
+        * vapor_pressure_deficit
+        / kilopascals;
+    (radiation_term + aerodynamic_term)
+        / (slope + psychrometric_constant * (F::from_f64(1.0) + F::from_f64(0.34) * wind_speed))
+}
+
+fn unrounded_saturation_vapor_pressure<F: Float>(temperature: &F) -> F {
+    let coefs = FAO_SATURATION_VAPOR_PRESSURE_COEFS.map(F::from_f64);
+    F::from_f64(10.0) * coefs[0] * (coefs[1] * *temperature / (*temperature + coefs[2])).exp()
+}
+
+fn unrounded_saturation_vapor_pressure_slope<F: Float>(temperature: &F) -> F {
+    let denominator = *temperature + F::from_f64(FAO_SATURATION_VAPOR_PRESSURE_COEFS[2]);
+    F::from_f64(4098.0) * unrounded_saturation_vapor_pressure(temperature)
+        / (denominator * denominator)
+}
+
+fn unrounded_psychrometric_constant<F: Float>(atmospheric_pressure: &F) -> F {
+    F::from_f64(0.000665) * *atmospheric_pressure
+}
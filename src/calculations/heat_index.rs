@@ -1,5 +1,5 @@
 use crate::constants::constants::ROTHFUSZ_COEFS;
-use crate::{celsius_to_fahrenheit, fahrenheit_to_celsius, meteo_round};
+use crate::{celsius_to_fahrenheit, fahrenheit_to_celsius, meteo_round, Float};
 
 /// Calculates heat index based on Rothfusz regression equation for Fahrenheits.
 ///
@@ -16,16 +16,18 @@ use crate::{celsius_to_fahrenheit, fahrenheit_to_celsius, meteo_round};
 ///
 /// assert_eq!(heat_index, result);
 /// ```
-pub fn fahrenheit_heat_index(temperature: &f64, relative_humidity: &f64) -> f64 {
-    let heat_index = ROTHFUSZ_COEFS[0]
-        + ROTHFUSZ_COEFS[1] * temperature
-        + ROTHFUSZ_COEFS[2] * relative_humidity
-        + ROTHFUSZ_COEFS[3] * temperature * relative_humidity
-        + ROTHFUSZ_COEFS[4] * temperature.powi(2)
-        + ROTHFUSZ_COEFS[5] * relative_humidity.powi(2)
-        + ROTHFUSZ_COEFS[6] * temperature.powi(2) * relative_humidity
-        + ROTHFUSZ_COEFS[7] * temperature * relative_humidity.powi(2)
-        + ROTHFUSZ_COEFS[8] * temperature.powi(2) * relative_humidity.powi(2);
+pub fn fahrenheit_heat_index<F: Float>(temperature: &F, relative_humidity: &F) -> F {
+    let coefs = ROTHFUSZ_COEFS.map(F::from_f64);
+    let (temperature, relative_humidity) = (*temperature, *relative_humidity);
+    let heat_index = coefs[0]
+        + coefs[1] * temperature
+        + coefs[2] * relative_humidity
+        + coefs[3] * temperature * relative_humidity
+        + coefs[4] * temperature.powi(2)
+        + coefs[5] * relative_humidity.powi(2)
+        + coefs[6] * temperature.powi(2) * relative_humidity
+        + coefs[7] * temperature * relative_humidity.powi(2)
+        + coefs[8] * temperature.powi(2) * relative_humidity.powi(2);
     meteo_round(&heat_index)
 }
 
@@ -44,7 +46,7 @@ pub fn fahrenheit_heat_index(temperature: &f64, relative_humidity: &f64) -> f64
 ///
 /// assert_eq!(heat_index, result);
 /// ```
-pub fn celsius_heat_index(temperature: &f64, relative_humidity: &f64) -> f64 {
+pub fn celsius_heat_index<F: Float>(temperature: &F, relative_humidity: &F) -> F {
     let fahrenheit_temperature = celsius_to_fahrenheit(temperature);
     let heat_index = fahrenheit_heat_index(&fahrenheit_temperature, relative_humidity);
     let fahrenheit_heat_index = fahrenheit_to_celsius(&heat_index);
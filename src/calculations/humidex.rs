@@ -1,7 +1,7 @@
 use crate::constants::constants::HUMIDEX_CONSTANT_OFFSET;
 use crate::{
     celsius_dew_point, common_celsius_dew_point, common_fahrenheit_dew_point, fahrenheit_dew_point,
-    meteo_round,
+    meteo_round, Float,
 };
 
 /// Counts humidex for Celsius from given values. Uses common dew point algorithm.
@@ -19,10 +19,9 @@ use crate::{
 ///
 /// assert_eq!(celsius, result);
 /// ```
-pub fn common_celsius_humidex(temperature: &f64, relative_humidity: &f64) -> f64 {
+pub fn common_celsius_humidex<F: Float>(temperature: &F, relative_humidity: &F) -> F {
     let dew_point = common_celsius_dew_point(temperature, relative_humidity);
-    let humidex = *temperature + (5.0 / 9.0) * (dew_point - HUMIDEX_CONSTANT_OFFSET);
-    meteo_round(&humidex)
+    humidex_from_dew_point(temperature, &dew_point)
 }
 
 /// Counts humidex for Celsius from given values. Uses Celsius with given atmospheric pressure correction in hPa.
@@ -42,14 +41,13 @@ pub fn common_celsius_humidex(temperature: &f64, relative_humidity: &f64) -> f64
 ///
 /// assert_eq!(celsius, result);
 /// ```
-pub fn celsius_humidex(
-    temperature: &f64,
-    relative_humidity: &f64,
-    atmospheric_pressure: &f64,
-) -> f64 {
+pub fn celsius_humidex<F: Float>(
+    temperature: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
     let dew_point = celsius_dew_point(temperature, relative_humidity, atmospheric_pressure);
-    let humidex = *temperature + (5.0 / 9.0) * (dew_point - HUMIDEX_CONSTANT_OFFSET);
-    meteo_round(&humidex)
+    humidex_from_dew_point(temperature, &dew_point)
 }
 
 /// Counts humidex for Fahrenheit from given values. Uses common dew point algorithm.
@@ -67,10 +65,9 @@ pub fn celsius_humidex(
 ///
 /// assert_eq!(fahrenheit, result);
 /// ```
-pub fn common_fahrenheit_humidex(temperature: &f64, relative_humidity: &f64) -> f64 {
+pub fn common_fahrenheit_humidex<F: Float>(temperature: &F, relative_humidity: &F) -> F {
     let dew_point = common_fahrenheit_dew_point(temperature, relative_humidity);
-    let humidex = *temperature + (5.0 / 9.0) * (dew_point - HUMIDEX_CONSTANT_OFFSET);
-    meteo_round(&humidex)
+    humidex_from_dew_point(temperature, &dew_point)
 }
 
 /// Counts humidex for Fahrenheit from given values. Uses Fahrenheit with given atmospheric pressure correction in hPa.
@@ -89,12 +86,18 @@ pub fn common_fahrenheit_humidex(temperature: &f64, relative_humidity: &f64) ->
 ///
 /// assert_eq!(fahrenheit, result);
 /// ```
-pub fn fahrenheit_humidex(
-    temperature: &f64,
-    relative_humidity: &f64,
-    atmospheric_pressure: &f64,
-) -> f64 {
+pub fn fahrenheit_humidex<F: Float>(
+    temperature: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
     let dew_point = fahrenheit_dew_point(temperature, relative_humidity, atmospheric_pressure);
-    let humidex = *temperature + (5.0 / 9.0) * (dew_point - HUMIDEX_CONSTANT_OFFSET);
+    humidex_from_dew_point(temperature, &dew_point)
+}
+
+// Humidex from temperature and dew point, both in the same scale
+pub(crate) fn humidex_from_dew_point<F: Float>(temperature: &F, dew_point: &F) -> F {
+    let humidex =
+        *temperature + F::from_f64(5.0 / 9.0) * (*dew_point - F::from_f64(HUMIDEX_CONSTANT_OFFSET));
     meteo_round(&humidex)
 }
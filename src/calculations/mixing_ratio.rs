@@ -1,33 +1,35 @@
-use crate::constants::constants::AVG_ATMOSPHERIC_PRESSURE;
-use crate::{fahrenheit_to_celsius, meteo_round, saturation_vapor_pressure};
+use crate::constants::constants::{AVG_ATMOSPHERIC_PRESSURE, MOLAR_MASS_RATIO};
+use crate::{fahrenheit_to_celsius, meteo_round, saturation_vapor_pressure, Float};
 
 /// Calculates mixing ratio using Magnus-Tetens formula using Celsius with common atmospheric pressure using constant.
 ///
-/// Returns g/kg
+/// Returns kg/kg
 ///
 /// # Examples
 ///
 /// ```
 /// let temperature = 22.5;
 /// let relative_humidity = 62.4;
-/// let result = 0.0826;
+/// let result = 0.0106;
 ///
 /// let mixing_ratio = meteo_tools::common_celsius_mixing_ratio(&temperature, &relative_humidity);
 ///
 /// assert_eq!(mixing_ratio, result);
 /// ```
-pub fn common_celsius_mixing_ratio(temperature: &f64, relative_humidity: &f64) -> f64 {
+pub fn common_celsius_mixing_ratio<F: Float>(temperature: &F, relative_humidity: &F) -> F {
     let saturation_vapor_pressure = saturation_vapor_pressure(temperature);
-    let actual_vapor_pressure = saturation_vapor_pressure * (relative_humidity / 100.0);
+    let actual_vapor_pressure =
+        saturation_vapor_pressure * (*relative_humidity / F::from_f64(100.0));
 
-    let mixing_ratio =
-        0.622 * (actual_vapor_pressure / (AVG_ATMOSPHERIC_PRESSURE - actual_vapor_pressure));
-    meteo_round(&mixing_ratio)
+    mixing_ratio_from_vapor_pressure(
+        &actual_vapor_pressure,
+        &F::from_f64(AVG_ATMOSPHERIC_PRESSURE),
+    )
 }
 
 /// Calculates mixing ratio using Magnus-Tetens formula using Celsius with precise atm. pressure given.
 ///
-/// Returns g/kg
+/// Returns kg/kg
 ///
 /// # Examples
 ///
@@ -35,41 +37,40 @@ pub fn common_celsius_mixing_ratio(temperature: &f64, relative_humidity: &f64) -
 /// let temperature = 22.5;
 /// let relative_humidity = 62.4;
 /// let atmospheric_pressure = 1013.25;
-/// let result = 0.0826;
+/// let result = 0.0106;
 ///
 /// let mixing_ratio = meteo_tools::celsius_mixing_ratio(&temperature, &relative_humidity, &atmospheric_pressure);
 ///
 /// assert_eq!(mixing_ratio, result);
 /// ```
-pub fn celsius_mixing_ratio(
-    temperature: &f64,
-    relative_humidity: &f64,
-    atmospheric_pressure: &f64,
-) -> f64 {
+pub fn celsius_mixing_ratio<F: Float>(
+    temperature: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
     let saturation_vapor_pressure = saturation_vapor_pressure(temperature);
-    let actual_vapor_pressure = saturation_vapor_pressure * (relative_humidity / 100.0);
+    let actual_vapor_pressure =
+        saturation_vapor_pressure * (*relative_humidity / F::from_f64(100.0));
 
-    let mixing_ratio =
-        0.622 * (actual_vapor_pressure / (atmospheric_pressure - actual_vapor_pressure));
-    meteo_round(&mixing_ratio)
+    mixing_ratio_from_vapor_pressure(&actual_vapor_pressure, atmospheric_pressure)
 }
 
 /// Calculates mixing ratio using Magnus-Tetens formula using Celsius with common atmospheric pressure using constant.
 ///
-/// Returns g/kg
+/// Returns kg/kg
 ///
 /// # Examples
 ///
 /// ```
 /// let temperature = 72.5;
 /// let relative_humidity = 62.4;
-/// let result = 0.0826;
+/// let result = 0.0106;
 ///
 /// let mixing_ratio = meteo_tools::common_fahrenheit_mixing_ratio(&temperature, &relative_humidity);
 ///
 /// assert_eq!(mixing_ratio, result);
 /// ```
-pub fn common_fahrenheit_mixing_ratio(temperature: &f64, relative_humidity: &f64) -> f64 {
+pub fn common_fahrenheit_mixing_ratio<F: Float>(temperature: &F, relative_humidity: &F) -> F {
     let temperature_celsius = fahrenheit_to_celsius(temperature);
     let mixing_ratio = common_celsius_mixing_ratio(&temperature_celsius, relative_humidity);
     meteo_round(&mixing_ratio)
@@ -77,7 +78,7 @@ pub fn common_fahrenheit_mixing_ratio(temperature: &f64, relative_humidity: &f64
 
 /// Calculates mixing ratio using Magnus-Tetens formula using Celsius with with precise atm. pressure given.
 ///
-/// Returns g/kg
+/// Returns kg/kg
 ///
 /// # Examples
 ///
@@ -85,17 +86,17 @@ pub fn common_fahrenheit_mixing_ratio(temperature: &f64, relative_humidity: &f64
 /// let temperature = 72.5;
 /// let relative_humidity = 62.4;
 /// let atmospheric_pressure = 1013.25;
-/// let result = 0.0826;
+/// let result = 0.0106;
 ///
 /// let mixing_ratio = meteo_tools::common_fahrenheit_mixing_ratio(&temperature, &relative_humidity);
 ///
 /// assert_eq!(mixing_ratio, result);
 /// ```
-pub fn fahrenheit_mixing_ratio(
-    temperature: &f64,
-    relative_humidity: &f64,
-    atmospheric_pressure: &f64,
-) -> f64 {
+pub fn fahrenheit_mixing_ratio<F: Float>(
+    temperature: &F,
+    relative_humidity: &F,
+    atmospheric_pressure: &F,
+) -> F {
     let temperature_celsius = fahrenheit_to_celsius(temperature);
     let mixing_ratio = celsius_mixing_ratio(
         &temperature_celsius,
@@ -104,3 +105,13 @@ pub fn fahrenheit_mixing_ratio(
     );
     meteo_round(&mixing_ratio)
 }
+
+// Mixing ratio from actual vapor pressure and atmospheric pressure, both in hPa
+pub(crate) fn mixing_ratio_from_vapor_pressure<F: Float>(
+    vapor_pressure: &F,
+    atmospheric_pressure: &F,
+) -> F {
+    let mixing_ratio = F::from_f64(MOLAR_MASS_RATIO)
+        * (*vapor_pressure / (*atmospheric_pressure - *vapor_pressure));
+    meteo_round(&mixing_ratio)
+}
@@ -0,0 +1,280 @@
+use core::f64::consts::PI;
+
+use crate::calculations::solar::day_of_year;
+use crate::constants::constants::{
+    ANGSTROM_COEFS, HAURWITZ_COEFS, INEICHEN_COEFS, KASTEN_YOUNG_COEFS, SOLAR_CONSTANT,
+    SOLAR_IRRADIANCE_CONSTANT,
+};
+use crate::{meteo_round, pressure_at_elevation, solar_position, Float};
+
+/// Calculates daily extraterrestrial radiation on a horizontal surface using FAO-56 equation 21.
+///
+/// Takes latitude in degrees and day of the year, returns radiation in MJ/m² per day
+///
+/// # Examples
+///
+/// ```
+/// // FAO-56 example 8, 20°S on 3 September
+/// let latitude = -20.0;
+/// let day_of_year = 246;
+/// let result = 32.194;
+///
+/// let radiation = meteo_tools::extraterrestrial_radiation(&latitude, day_of_year);
+///
+/// assert_eq!(radiation, result);
+/// ```
+pub fn extraterrestrial_radiation<F: Float>(latitude: &F, day_of_year: u16) -> F {
+    meteo_round(&unrounded_extraterrestrial_radiation(latitude, day_of_year))
+}
+
+/// Calculates extraterrestrial irradiance on a horizontal surface at given place and time.
+///
+/// Takes latitude and longitude in degrees and Unix timestamp in seconds, returns irradiance in W/m², 0 at night
+///
+/// # Examples
+///
+/// ```
+/// // Greenwich observatory, 21 June 2024 12:00 UTC
+/// let latitude = 51.4769;
+/// let longitude = 0.0;
+/// let timestamp = 1718971200;
+/// let result = 1167.2336;
+///
+/// let irradiance = meteo_tools::extraterrestrial_irradiance(&latitude, &longitude, timestamp);
+///
+/// assert_eq!(irradiance, result);
+/// ```
+pub fn extraterrestrial_irradiance<F: Float>(latitude: &F, longitude: &F, timestamp: i64) -> F {
+    let position = solar_position(latitude, longitude, timestamp);
+    let cos_zenith = Float::cos(position.zenith.to_f64().to_radians()).max(0.0);
+    F::from_f64(meteo_round(&(normal_irradiance(timestamp) * cos_zenith)))
+}
+
+/// Calculates daily clear-sky solar radiation from extraterrestrial radiation using FAO-56 equation 37.
+///
+/// Takes extraterrestrial radiation in any unit and elevation in meters, returns radiation in the same unit
+///
+/// # Examples
+///
+/// ```
+/// let extraterrestrial_radiation = 32.2;
+/// let elevation = 100.0;
+/// let result = 24.2144;
+///
+/// let radiation = meteo_tools::fao_clear_sky_radiation(&extraterrestrial_radiation, &elevation);
+///
+/// assert_eq!(radiation, result);
+/// ```
+pub fn fao_clear_sky_radiation<F: Float>(extraterrestrial_radiation: &F, elevation: &F) -> F {
+    meteo_round(&unrounded_clear_sky_radiation(
+        extraterrestrial_radiation,
+        elevation,
+    ))
+}
+
+/// Calculates clear-sky global horizontal irradiance using Haurwitz model, which depends on the solar zenith only.
+///
+/// Takes latitude and longitude in degrees and Unix timestamp in seconds, returns irradiance in W/m², 0 at night
+///
+/// # Examples
+///
+/// ```
+/// // Greenwich observatory, 21 June 2024 12:00 UTC
+/// let latitude = 51.4769;
+/// let longitude = 0.0;
+/// let timestamp = 1718971200;
+/// let result = 906.5105;
+///
+/// let irradiance = meteo_tools::haurwitz_clear_sky_irradiance(&latitude, &longitude, timestamp);
+///
+/// assert_eq!(irradiance, result);
+/// ```
+pub fn haurwitz_clear_sky_irradiance<F: Float>(latitude: &F, longitude: &F, timestamp: i64) -> F {
+    let position = solar_position(latitude, longitude, timestamp);
+    let cos_zenith = Float::sin(position.apparent_elevation.to_f64().to_radians());
+    if cos_zenith <= 0.0 {
+        return F::from_f64(0.0);
+    }
+    let [scale, extinction] = HAURWITZ_COEFS;
+    let irradiance = scale * cos_zenith * Float::exp(-extinction / cos_zenith);
+    F::from_f64(meteo_round(&irradiance))
+}
+
+/// Calculates clear-sky global horizontal irradiance using Ineichen-Perez model.
+///
+/// Takes latitude and longitude in degrees, Unix timestamp in seconds, elevation in meters and Linke turbidity,
+/// which is about 2 for very clean, 3 for typical rural and 4-6 for polluted air.
+/// Returns irradiance in W/m², 0 at night
+///
+/// # Examples
+///
+/// ```
+/// // Greenwich observatory, 21 June 2024 12:00 UTC
+/// let latitude = 51.4769;
+/// let longitude = 0.0;
+/// let timestamp = 1718971200;
+/// let elevation = 47.0;
+/// let linke_turbidity = 3.0;
+/// let result = 889.2121;
+///
+/// let irradiance = meteo_tools::ineichen_clear_sky_irradiance(&latitude, &longitude, timestamp, &elevation, &linke_turbidity);
+///
+/// assert_eq!(irradiance, result);
+/// ```
+pub fn ineichen_clear_sky_irradiance<F: Float>(
+    latitude: &F,
+    longitude: &F,
+    timestamp: i64,
+    elevation: &F,
+    linke_turbidity: &F,
+) -> F {
+    let position = solar_position(latitude, longitude, timestamp);
+    let apparent_zenith = 90.0 - position.apparent_elevation.to_f64();
+    let cos_zenith = Float::cos(apparent_zenith.to_radians());
+    if cos_zenith <= 0.0 {
+        return F::from_f64(0.0);
+    }
+    let elevation = elevation.to_f64();
+    let [airmass_coefficient, airmass_offset, airmass_exponent] = KASTEN_YOUNG_COEFS;
+    let relative_airmass = 1.0
+        / (cos_zenith
+            + airmass_coefficient
+                * Float::powf(airmass_offset - apparent_zenith, -airmass_exponent));
+    let absolute_airmass = relative_airmass * pressure_at_elevation(&elevation) / 1013.0;
+
+    let [cg1_slope, cg1_offset, cg2_slope, cg2_offset] = INEICHEN_COEFS;
+    let cg1 = cg1_slope * elevation + cg1_offset;
+    let cg2 = cg2_slope * elevation + cg2_offset;
+    let fh1 = Float::exp(-elevation / 8000.0);
+    let fh2 = Float::exp(-elevation / 1250.0);
+    let transmittance =
+        Float::exp(-cg2 * absolute_airmass * (fh1 + fh2 * (linke_turbidity.to_f64() - 1.0)));
+    let irradiance = cg1 * normal_irradiance(timestamp) * cos_zenith * transmittance.max(0.0);
+    F::from_f64(meteo_round(&irradiance))
+}
+
+/// Calculates clearness index, the ratio of measured to extraterrestrial radiation.
+///
+/// Both radiations must be in the same unit. Returns 0 when the extraterrestrial radiation is 0 (at night).
+///
+/// # Examples
+///
+/// ```
+/// let solar_radiation = 22.07;
+/// let extraterrestrial_radiation = 41.09;
+/// let result = 0.5371;
+///
+/// let clearness_index = meteo_tools::clearness_index(&solar_radiation, &extraterrestrial_radiation);
+///
+/// assert_eq!(clearness_index, result);
+/// ```
+pub fn clearness_index<F: Float>(solar_radiation: &F, extraterrestrial_radiation: &F) -> F {
+    if *extraterrestrial_radiation <= F::from_f64(0.0) {
+        return F::from_f64(0.0);
+    }
+    meteo_round(&(*solar_radiation / *extraterrestrial_radiation))
+}
+
+/// Estimates daily solar radiation from sunshine duration using Angström-Prescott equation (FAO-56 equation 35).
+///
+/// Takes sunshine duration in hours, latitude in degrees and day of the year, returns radiation in MJ/m² per day
+///
+/// # Examples
+///
+/// ```
+/// // FAO-56 example 10, Rio de Janeiro in May
+/// let sunshine_duration = 7.1;
+/// let latitude = -22.9;
+/// let day_of_year = 135;
+/// let result = 14.4598;
+///
+/// let radiation = meteo_tools::angstrom_solar_radiation(&sunshine_duration, &latitude, day_of_year);
+///
+/// assert_eq!(radiation, result);
+/// ```
+pub fn angstrom_solar_radiation<F: Float>(
+    sunshine_duration: &F,
+    latitude: &F,
+    day_of_year: u16,
+) -> F {
+    let [a, b] = ANGSTROM_COEFS.map(F::from_f64);
+    let daylight_hours = F::from_f64(daylight_hours(latitude.to_f64(), day_of_year));
+    let relative_duration = if daylight_hours > F::from_f64(0.0) {
+        *sunshine_duration / daylight_hours
+    } else {
+        F::from_f64(0.0)
+    };
+    let radiation =
+        (a + b * relative_duration) * unrounded_extraterrestrial_radiation(latitude, day_of_year);
+    meteo_round(&radiation)
+}
+
+/// Estimates daily sunshine duration from solar radiation by inverting Angström-Prescott equation.
+///
+/// Takes solar radiation in MJ/m² per day, latitude in degrees and day of the year,
+/// returns hours between 0 and the daylight hours
+///
+/// # Examples
+///
+/// ```
+/// let solar_radiation = 14.4598;
+/// let latitude = -22.9;
+/// let day_of_year = 135;
+/// let result = 7.1;
+///
+/// let sunshine_duration = meteo_tools::sunshine_duration(&solar_radiation, &latitude, day_of_year);
+///
+/// assert_eq!(sunshine_duration, result);
+/// ```
+pub fn sunshine_duration<F: Float>(solar_radiation: &F, latitude: &F, day_of_year: u16) -> F {
+    let [a, b] = ANGSTROM_COEFS;
+    let extraterrestrial = unrounded_extraterrestrial_radiation(latitude, day_of_year).to_f64();
+    if extraterrestrial <= 0.0 {
+        return F::from_f64(0.0);
+    }
+    let daylight_hours = daylight_hours(latitude.to_f64(), day_of_year);
+    let relative_duration = ((solar_radiation.to_f64() / extraterrestrial - a) / b).clamp(0.0, 1.0);
+    F::from_f64(meteo_round(&(relative_duration * daylight_hours)))
+}
+
+pub(crate) fn unrounded_extraterrestrial_radiation<F: Float>(latitude: &F, day_of_year: u16) -> F {
+    let latitude = latitude.to_f64().to_radians();
+    let (declination, sunset_angle) = daily_sun(latitude, day_of_year);
+    let radiation = 24.0 * 60.0 / PI
+        * SOLAR_CONSTANT
+        * inverse_relative_distance(day_of_year)
+        * (sunset_angle * Float::sin(latitude) * Float::sin(declination)
+            + Float::cos(latitude) * Float::cos(declination) * Float::sin(sunset_angle));
+    F::from_f64(radiation)
+}
+
+pub(crate) fn unrounded_clear_sky_radiation<F: Float>(
+    extraterrestrial_radiation: &F,
+    elevation: &F,
+) -> F {
+    (F::from_f64(0.75) + F::from_f64(2e-5) * *elevation) * *extraterrestrial_radiation
+}
+
+// Daylight hours using FAO-56 equation 34
+fn daylight_hours(latitude: f64, day_of_year: u16) -> f64 {
+    let (_, sunset_angle) = daily_sun(latitude.to_radians(), day_of_year);
+    24.0 / PI * sunset_angle
+}
+
+// Solar declination and sunset hour angle in radians using FAO-56 equations 24 and 25
+fn daily_sun(latitude: f64, day_of_year: u16) -> (f64, f64) {
+    let declination = 0.409 * Float::sin(2.0 * PI * day_of_year as f64 / 365.0 - 1.39);
+    let sunset_angle =
+        Float::acos((-Float::tan(latitude) * Float::tan(declination)).clamp(-1.0, 1.0));
+    (declination, sunset_angle)
+}
+
+// Inverse relative distance Earth-Sun using FAO-56 equation 23
+fn inverse_relative_distance(day_of_year: u16) -> f64 {
+    1.0 + 0.033 * Float::cos(2.0 * PI * day_of_year as f64 / 365.0)
+}
+
+// Extraterrestrial irradiance normal to the sun's rays in W/m²
+fn normal_irradiance(timestamp: i64) -> f64 {
+    SOLAR_IRRADIANCE_CONSTANT * inverse_relative_distance(day_of_year(timestamp))
+}
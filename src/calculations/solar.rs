@@ -0,0 +1,381 @@
+use crate::constants::constants::{JULIAN_DAY_UNIX_EPOCH, SECONDS_PER_DAY};
+use crate::{meteo_round, Float};
+
+/// Position of the sun in the sky at a given place and time.
+///
+/// All angles are in degrees, equation of time is in minutes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolarPosition<F = f64> {
+    /// Declination of the sun, positive when the sun is north of the equator.
+    pub declination: F,
+    /// Difference between apparent and mean solar time.
+    pub equation_of_time: F,
+    /// Hour angle, negative before and positive after solar noon.
+    pub hour_angle: F,
+    /// Angle between the sun and the zenith without atmospheric refraction.
+    pub zenith: F,
+    /// Angle of the sun above the horizon without atmospheric refraction.
+    pub elevation: F,
+    /// Angle of the sun above the horizon as seen through the atmosphere.
+    pub apparent_elevation: F,
+    /// Azimuth clockwise from north.
+    pub azimuth: F,
+}
+
+/// Horizon used for sunrise, sunset and twilight times, given by the position of the sun's centre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SunHorizon {
+    /// Upper limb of the sun on the horizon, 0.833° below it with refraction.
+    Sunrise,
+    /// Civil twilight, 6° below the horizon.
+    Civil,
+    /// Nautical twilight, 12° below the horizon.
+    Nautical,
+    /// Astronomical twilight, 18° below the horizon.
+    Astronomical,
+}
+
+impl SunHorizon {
+    /// Returns zenith angle of the sun's centre in degrees.
+    pub fn zenith(&self) -> f64 {
+        match self {
+            SunHorizon::Sunrise => 90.833,
+            SunHorizon::Civil => 96.0,
+            SunHorizon::Nautical => 102.0,
+            SunHorizon::Astronomical => 108.0,
+        }
+    }
+}
+
+/// Times when the sun crosses a horizon during a day, as Unix timestamps in seconds.
+///
+/// For a twilight [`SunHorizon`] rise is the start of the morning twilight and set the end of the evening twilight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SunTimes {
+    /// Sun rises above and sets below the horizon.
+    RiseAndSet {
+        /// Time the sun rises above the horizon.
+        rise: i64,
+        /// Time the sun sets below the horizon.
+        set: i64,
+    },
+    /// Sun stays above the horizon all day. For [`SunHorizon::Sunrise`] this is the polar day,
+    /// for a twilight horizon the twilight lasts all night, as in summer nights at high latitudes.
+    AlwaysAbove,
+    /// Sun stays below the horizon all day. For [`SunHorizon::Sunrise`] this is the polar night,
+    /// for a twilight horizon the sky does not get as light as the twilight all day.
+    AlwaysBelow,
+}
+
+/// Calculates declination of the sun at given Unix timestamp in seconds.
+///
+/// Returns degrees, positive when the sun is north of the equator
+///
+/// # Examples
+///
+/// ```
+/// // 21 June 2024 12:00 UTC
+/// let timestamp = 1718971200;
+/// let result = 23.4372;
+///
+/// let declination: f64 = meteo_tools::solar_declination(timestamp);
+///
+/// assert_eq!(declination, result);
+/// ```
+pub fn solar_declination<F: Float>(timestamp: i64) -> F {
+    F::from_f64(meteo_round(&SunOrbit::at(timestamp).declination))
+}
+
+/// Calculates equation of time, the difference between apparent and mean solar time, at given Unix timestamp in seconds.
+///
+/// Returns minutes
+///
+/// # Examples
+///
+/// ```
+/// // 3 November 2024 12:00 UTC
+/// let timestamp = 1730635200;
+/// let result = 16.4882;
+///
+/// let equation_of_time: f64 = meteo_tools::equation_of_time(timestamp);
+///
+/// assert_eq!(equation_of_time, result);
+/// ```
+pub fn equation_of_time<F: Float>(timestamp: i64) -> F {
+    F::from_f64(meteo_round(&SunOrbit::at(timestamp).equation_of_time))
+}
+
+/// Calculates position of the sun using the NOAA solar calculator algorithm.
+///
+/// Takes latitude and longitude in degrees (positive north and east) and Unix timestamp in seconds.
+/// Accuracy is within a minute of arc for dates between 1800 and 2100.
+///
+/// # Examples
+///
+/// ```
+/// // Greenwich observatory, 21 June 2024 12:00 UTC
+/// let latitude = 51.4769;
+/// let longitude = 0.0;
+/// let timestamp = 1718971200;
+///
+/// let position = meteo_tools::solar_position(&latitude, &longitude, timestamp);
+///
+/// assert_eq!(position.elevation, 61.9579);
+/// assert_eq!(position.azimuth, 179.0608);
+/// ```
+pub fn solar_position<F: Float>(latitude: &F, longitude: &F, timestamp: i64) -> SolarPosition<F> {
+    let orbit = SunOrbit::at(timestamp);
+    let latitude = latitude.to_f64().to_radians();
+    let declination = orbit.declination.to_radians();
+
+    let minutes = timestamp.rem_euclid(SECONDS_PER_DAY) as f64 / 60.0;
+    let true_solar_time =
+        (minutes + orbit.equation_of_time + 4.0 * longitude.to_f64()).rem_euclid(1440.0);
+    let hour_angle = true_solar_time / 4.0 - 180.0;
+
+    let cos_zenith = (Float::sin(latitude) * Float::sin(declination)
+        + Float::cos(latitude) * Float::cos(declination) * Float::cos(hour_angle.to_radians()))
+    .clamp(-1.0, 1.0);
+    let zenith = Float::acos(cos_zenith);
+    let elevation = 90.0 - zenith.to_degrees();
+
+    let sin_zenith = Float::sin(zenith);
+    let azimuth = if sin_zenith == 0.0 {
+        if latitude >= declination {
+            180.0
+        } else {
+            0.0
+        }
+    } else {
+        let cos_azimuth = ((Float::sin(latitude) * cos_zenith - Float::sin(declination))
+            / (Float::cos(latitude) * sin_zenith))
+            .clamp(-1.0, 1.0);
+        let angle = Float::acos(cos_azimuth).to_degrees();
+        if hour_angle > 0.0 {
+            (angle + 180.0).rem_euclid(360.0)
+        } else {
+            (540.0 - angle).rem_euclid(360.0)
+        }
+    };
+
+    let round = |value: f64| F::from_f64(meteo_round(&value));
+    SolarPosition {
+        declination: round(orbit.declination),
+        equation_of_time: round(orbit.equation_of_time),
+        hour_angle: round(hour_angle),
+        zenith: round(zenith.to_degrees()),
+        elevation: round(elevation),
+        apparent_elevation: round(elevation + atmospheric_refraction(elevation)),
+        azimuth: round(azimuth),
+    }
+}
+
+/// Calculates solar noon of the UTC day of given Unix timestamp at given longitude in degrees.
+///
+/// Returns Unix timestamp in seconds
+///
+/// # Examples
+///
+/// ```
+/// // Prague, 21 June 2024
+/// let longitude = 14.4208;
+/// let timestamp = 1718971200;
+/// let result = 1718967854;
+///
+/// let noon = meteo_tools::solar_noon(&longitude, timestamp);
+///
+/// assert_eq!(noon, result);
+/// ```
+pub fn solar_noon<F: Float>(longitude: &F, timestamp: i64) -> i64 {
+    let day_start = timestamp - timestamp.rem_euclid(SECONDS_PER_DAY);
+    let longitude = longitude.to_f64();
+    let mut noon = day_start + SECONDS_PER_DAY / 2;
+    // Second pass evaluates the equation of time at the first estimate of noon
+    for _ in 0..2 {
+        let equation_of_time = SunOrbit::at(noon).equation_of_time;
+        let minutes = 720.0 - 4.0 * longitude - equation_of_time;
+        noon = day_start + Float::round(minutes * 60.0) as i64;
+    }
+    noon
+}
+
+/// Calculates times when the sun crosses given horizon around the solar noon of the UTC day of given Unix timestamp.
+///
+/// Takes latitude and longitude in degrees (positive north and east), returns Unix timestamps in seconds.
+/// Far from the prime meridian sunrise or sunset may fall on the neighbouring UTC day.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{SunHorizon, SunTimes};
+///
+/// // Greenwich observatory, 21 June 2024
+/// let latitude = 51.4769;
+/// let longitude = 0.0;
+/// let timestamp = 1718971200;
+///
+/// let sun = meteo_tools::sun_times(&latitude, &longitude, timestamp, SunHorizon::Sunrise);
+/// let twilight = meteo_tools::sun_times(&latitude, &longitude, timestamp, SunHorizon::Astronomical);
+///
+/// // 03:42:51 and 20:20:59 UTC
+/// assert_eq!(sun, SunTimes::RiseAndSet { rise: 1718941371, set: 1719001259 });
+/// assert_eq!(twilight, SunTimes::AlwaysAbove);
+/// ```
+pub fn sun_times<F: Float>(
+    latitude: &F,
+    longitude: &F,
+    timestamp: i64,
+    horizon: SunHorizon,
+) -> SunTimes {
+    let noon = solar_noon(longitude, timestamp);
+    let rise = sun_crossing(latitude.to_f64(), longitude.to_f64(), noon, horizon, -1.0);
+    let set = sun_crossing(latitude.to_f64(), longitude.to_f64(), noon, horizon, 1.0);
+    match (rise, set) {
+        (Ok(rise), Ok(set)) => SunTimes::RiseAndSet { rise, set },
+        (Err(always), _) | (_, Err(always)) => always,
+    }
+}
+
+/// Calculates length of the day between sunrise and sunset during the UTC day of given Unix timestamp.
+///
+/// Returns hours, 24 for polar day and 0 for polar night
+///
+/// # Examples
+///
+/// ```
+/// // Prague, 21 December 2024
+/// let latitude = 50.0755;
+/// let longitude = 14.4378;
+/// let timestamp = 1734782400;
+/// let result = 8.0611;
+///
+/// let day_length = meteo_tools::day_length(&latitude, &longitude, timestamp);
+///
+/// assert_eq!(day_length, result);
+/// ```
+pub fn day_length<F: Float>(latitude: &F, longitude: &F, timestamp: i64) -> F {
+    let hours = match sun_times(latitude, longitude, timestamp, SunHorizon::Sunrise) {
+        SunTimes::RiseAndSet { rise, set } => (set - rise) as f64 / 3600.0,
+        SunTimes::AlwaysAbove => 24.0,
+        SunTimes::AlwaysBelow => 0.0,
+    };
+    F::from_f64(meteo_round(&hours))
+}
+
+// Time of sunrise (direction -1) or sunset (direction 1) around solar noon,
+// second pass evaluates the orbit at the first estimate of the crossing
+fn sun_crossing(
+    latitude: f64,
+    longitude: f64,
+    noon: i64,
+    horizon: SunHorizon,
+    direction: f64,
+) -> Result<i64, SunTimes> {
+    let latitude = latitude.to_radians();
+    let day_start = noon - noon.rem_euclid(SECONDS_PER_DAY);
+    let mut crossing = noon;
+    for _ in 0..2 {
+        let orbit = SunOrbit::at(crossing);
+        let declination = orbit.declination.to_radians();
+        let cos_hour_angle = Float::cos(horizon.zenith().to_radians())
+            / (Float::cos(latitude) * Float::cos(declination))
+            - Float::tan(latitude) * Float::tan(declination);
+        if cos_hour_angle < -1.0 {
+            return Err(SunTimes::AlwaysAbove);
+        }
+        if cos_hour_angle > 1.0 {
+            return Err(SunTimes::AlwaysBelow);
+        }
+        let hour_angle = Float::acos(cos_hour_angle).to_degrees();
+        let minutes = 720.0 - 4.0 * (longitude - direction * hour_angle) - orbit.equation_of_time;
+        crossing = day_start + Float::round(minutes * 60.0) as i64;
+    }
+    Ok(crossing)
+}
+
+// Atmospheric refraction in degrees for geometric elevation in degrees, as used by NOAA
+fn atmospheric_refraction(elevation: f64) -> f64 {
+    let tangent = Float::tan(elevation.to_radians());
+    let arc_seconds = if elevation > 85.0 {
+        0.0
+    } else if elevation > 5.0 {
+        58.1 / tangent - 0.07 / Float::powi(tangent, 3) + 0.000086 / Float::powi(tangent, 5)
+    } else if elevation > -0.575 {
+        1735.0
+            + elevation * (-518.2 + elevation * (103.4 + elevation * (-12.79 + elevation * 0.711)))
+    } else {
+        -20.772 / tangent
+    };
+    arc_seconds / 3600.0
+}
+
+// Declination and equation of time of the NOAA solar calculator
+struct SunOrbit {
+    declination: f64,
+    equation_of_time: f64,
+}
+
+impl SunOrbit {
+    fn at(timestamp: i64) -> Self {
+        let julian_day = timestamp as f64 / SECONDS_PER_DAY as f64 + JULIAN_DAY_UNIX_EPOCH;
+        let century = (julian_day - 2451545.0) / 36525.0;
+
+        let mean_longitude =
+            (280.46646 + century * (36000.76983 + century * 0.0003032)).rem_euclid(360.0);
+        let mean_anomaly = 357.52911 + century * (35999.05029 - 0.0001537 * century);
+        let eccentricity = 0.016708634 - century * (0.000042037 + 0.0000001267 * century);
+        let anomaly = mean_anomaly.to_radians();
+        let center = Float::sin(anomaly) * (1.914602 - century * (0.004817 + 0.000014 * century))
+            + Float::sin(2.0 * anomaly) * (0.019993 - 0.000101 * century)
+            + Float::sin(3.0 * anomaly) * 0.000289;
+        let omega = (125.04 - 1934.136 * century).to_radians();
+        let apparent_longitude = mean_longitude + center - 0.00569 - 0.00478 * Float::sin(omega);
+        let mean_obliquity = 23.0
+            + (26.0
+                + (21.448 - century * (46.815 + century * (0.00059 - century * 0.001813))) / 60.0)
+                / 60.0;
+        let obliquity = (mean_obliquity + 0.00256 * Float::cos(omega)).to_radians();
+        let declination =
+            Float::asin(Float::sin(obliquity) * Float::sin(apparent_longitude.to_radians()));
+
+        let y = Float::powi(Float::tan(obliquity / 2.0), 2);
+        let longitude = mean_longitude.to_radians();
+        let equation_of_time = 4.0
+            * (y * Float::sin(2.0 * longitude) - 2.0 * eccentricity * Float::sin(anomaly)
+                + 4.0 * eccentricity * y * Float::sin(anomaly) * Float::cos(2.0 * longitude)
+                - 0.5 * y * y * Float::sin(4.0 * longitude)
+                - 1.25 * eccentricity * eccentricity * Float::sin(2.0 * anomaly))
+            .to_degrees();
+
+        SunOrbit {
+            declination: declination.to_degrees(),
+            equation_of_time,
+        }
+    }
+}
+
+// Day of the year of a Unix timestamp in UTC, 1 for 1 January
+pub(crate) fn day_of_year(timestamp: i64) -> u16 {
+    const DAYS_BEFORE_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    // Civil date from days since epoch, years starting in March (Hinnant's algorithm)
+    let days = timestamp.div_euclid(SECONDS_PER_DAY) + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_march_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_march_year + 2) / 153;
+    let day = day_of_march_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let leap_day = i64::from(leap && month > 2);
+    (DAYS_BEFORE_MONTH[month as usize - 1] + day + leap_day) as u16
+}
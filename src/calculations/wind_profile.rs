@@ -0,0 +1,173 @@
+use crate::constants::constants::{FAO_WIND_PROFILE_COEFS, POWER_LAW_EXPONENT};
+use crate::{Float, SpeedUnit};
+
+/// Aerodynamic roughness length of the terrain around the anemometer.
+///
+/// Presets follow the Davenport classification as used by WMO guide No. 8.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoughnessLength {
+    /// Open sea or lake, 0.0002 m.
+    Sea,
+    /// Mud flats, snow, featureless land, 0.005 m.
+    Smooth,
+    /// Flat grassland, airports, 0.03 m.
+    Open,
+    /// Low crops, occasional large obstacles, 0.1 m.
+    RoughlyOpen,
+    /// High crops, scattered obstacles, 0.25 m.
+    Rough,
+    /// Orchards, bushes, parkland, 0.5 m.
+    VeryRough,
+    /// Suburbs, villages, forest, 1.0 m.
+    Closed,
+    /// City centres with high and low rise buildings, 2.0 m.
+    Chaotic,
+    /// Custom roughness length in meters.
+    Custom(f64),
+}
+
+impl RoughnessLength {
+    /// Returns roughness length in meters.
+    pub fn meters(&self) -> f64 {
+        match self {
+            RoughnessLength::Sea => 0.0002,
+            RoughnessLength::Smooth => 0.005,
+            RoughnessLength::Open => 0.03,
+            RoughnessLength::RoughlyOpen => 0.1,
+            RoughnessLength::Rough => 0.25,
+            RoughnessLength::VeryRough => 0.5,
+            RoughnessLength::Closed => 1.0,
+            RoughnessLength::Chaotic => 2.0,
+            RoughnessLength::Custom(meters) => *meters,
+        }
+    }
+}
+
+/// Converts wind speed measured at one height to another height using logarithmic wind profile.
+/// Assumes neutral stability of the atmosphere. Heights are in meters above ground.
+///
+/// Takes speed in m/s, returns speed in given unit. The profile is defined only above the roughness length,
+/// returns NaN when either height is not above it.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{RoughnessLength, SpeedUnit};
+///
+/// let speed = 5.0;
+/// let measured_height = 3.0;
+/// let target_height = 10.0;
+/// let result = 6.3072;
+///
+/// let speed_10m = meteo_tools::log_profile_wind_speed(&speed, &measured_height, &target_height, RoughnessLength::Open, SpeedUnit::MetersPerSecond);
+///
+/// assert_eq!(speed_10m, result);
+///
+/// let below_roughness: f64 = meteo_tools::log_profile_wind_speed(&speed, &1.0, &target_height, RoughnessLength::Closed, SpeedUnit::MetersPerSecond);
+///
+/// assert!(below_roughness.is_nan());
+/// ```
+pub fn log_profile_wind_speed<F: Float>(
+    speed: &F,
+    measured_height: &F,
+    target_height: &F,
+    roughness: RoughnessLength,
+    unit: SpeedUnit,
+) -> F {
+    let roughness_length = F::from_f64(roughness.meters());
+    if *measured_height <= roughness_length || *target_height <= roughness_length {
+        return F::from_f64(f64::NAN);
+    }
+    let speed_at_height = *speed * (*target_height / roughness_length).ln()
+        / (*measured_height / roughness_length).ln();
+    unit.convert_mps(&speed_at_height)
+}
+
+/// Converts wind speed measured at one height to another height using power law with given exponent.
+/// Heights are in meters above ground.
+///
+/// Takes speed in m/s, returns speed in given unit
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::SpeedUnit;
+///
+/// let speed = 5.0;
+/// let measured_height = 3.0;
+/// let target_height = 10.0;
+/// let exponent = 0.2;
+/// let result = 14.2298;
+///
+/// let speed_10m = meteo_tools::power_law_wind_speed(&speed, &measured_height, &target_height, &exponent, SpeedUnit::MilesPerHour);
+///
+/// assert_eq!(speed_10m, result);
+/// ```
+pub fn power_law_wind_speed<F: Float>(
+    speed: &F,
+    measured_height: &F,
+    target_height: &F,
+    exponent: &F,
+    unit: SpeedUnit,
+) -> F {
+    let speed_at_height = *speed * (*target_height / *measured_height).powf(*exponent);
+    unit.convert_mps(&speed_at_height)
+}
+
+/// Converts wind speed measured at one height to another height using power law with common exponent 1/7.
+/// Heights are in meters above ground.
+///
+/// Takes speed in m/s, returns speed in given unit
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::SpeedUnit;
+///
+/// let speed = 5.0;
+/// let measured_height = 3.0;
+/// let target_height = 10.0;
+/// let result = 5.9384;
+///
+/// let speed_10m = meteo_tools::common_power_law_wind_speed(&speed, &measured_height, &target_height, SpeedUnit::MetersPerSecond);
+///
+/// assert_eq!(speed_10m, result);
+/// ```
+pub fn common_power_law_wind_speed<F: Float>(
+    speed: &F,
+    measured_height: &F,
+    target_height: &F,
+    unit: SpeedUnit,
+) -> F {
+    power_law_wind_speed(
+        speed,
+        measured_height,
+        target_height,
+        &F::from_f64(POWER_LAW_EXPONENT),
+        unit,
+    )
+}
+
+/// Converts wind speed measured at given height to 2 m above ground using FAO-56 logarithmic profile for short grass.
+///
+/// Takes speed in m/s, returns speed in given unit
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::SpeedUnit;
+///
+/// let speed = 5.0;
+/// let measured_height = 10.0;
+/// let result = 3.7398;
+///
+/// let speed_2m = meteo_tools::fao_wind_speed_at_2m(&speed, &measured_height, SpeedUnit::MetersPerSecond);
+///
+/// assert_eq!(speed_2m, result);
+/// ```
+pub fn fao_wind_speed_at_2m<F: Float>(speed: &F, measured_height: &F, unit: SpeedUnit) -> F {
+    let coefs = FAO_WIND_PROFILE_COEFS.map(F::from_f64);
+    let speed_2m = *speed * coefs[0] / (coefs[1] * *measured_height - coefs[2]).ln();
+    unit.convert_mps(&speed_2m)
+}
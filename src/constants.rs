@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod constants {
     pub const LATENT_HEAT_OF_VAPORIZATION: f64 = 17.27; // constant related to the latent heat of vaporization of water and the gas constant for water vapor
     pub const STANDARD_CONDENSATION_POINT: f64 = 237.7; // constant related to the temperature at which water vapor starts to condense at standard atmospheric pressure
@@ -21,10 +22,47 @@ pub mod constants {
     //pub const VOLUME_VAPOR_STD: f64 = 216.7; // specific volume of water vapor at standard temperature and pressure
     pub const GAS_CONSTANT: f64 = 8.314; // J/(mol·K)
     pub const MOLAR_MASS_WATER: f64 = 0.0180153; // kg/mol
-    pub const MMHG_CONVERSION: f64 = 0.750062;
-    pub const INHG_CONVERSION: f64 = 33.8639;
+    pub const MOLAR_MASS_RATIO: f64 = 0.622; // ratio of molar masses of water vapor and dry air
+    pub const BAROMETRIC_EXPONENT: f64 = 0.190284; // exponent of the barometric formula for the standard atmosphere
+    pub const PSYCHROMETER_COEFFICIENT: f64 = 0.000662; // 1/K, ventilated (Assmann) psychrometer
+    pub const PASCALS_PER_MMHG: f64 = 133.322387415;
+    pub const PASCALS_PER_INHG: f64 = 3386.389;
+    pub const PASCALS_PER_PSI: f64 = 6894.757293168;
+    pub const PASCALS_PER_ATM: f64 = 101325.0;
+    pub const PASCALS_PER_TORR: f64 = PASCALS_PER_ATM / 760.0;
     pub const METRIC_SPEED_CONVERSION: f64 = 3.6;
-    pub const IMPERIAL_SPEED_CONVERSION: f64 = 0.621371;
     pub const KNOTS_METRIC_SPEED_CONVERSION: f64 = 1.852;
-    pub const KNOTS_IMPERIAL_SPEED_CONVERSION: f64 = 1.15078;
+    pub const MPH_TO_MPS: f64 = 0.44704;
+    pub const FEET_TO_METERS: f64 = 0.3048;
+    pub const INCHES_TO_MILLIMETERS: f64 = 25.4;
+    pub const STATUTE_MILE_METERS: f64 = 1609.344;
+    pub const NAUTICAL_MILE_METERS: f64 = 1852.0;
+    pub const SPEED_OF_SOUND_COEFFICIENT: f64 = 20.0468; // sqrt of heat capacity ratio times specific gas constant of dry air
+    pub const BEAUFORT_COEFFICIENT: f64 = 0.836; // empirical Beaufort equation v = 0.836 B^(3/2) m/s
+    pub const POWER_LAW_EXPONENT: f64 = 1.0 / 7.0; // Hellmann exponent for neutral stability over open land
+    pub const FAO_WIND_PROFILE_COEFS: [f64; 3] = [4.87, 67.8, 5.42]; // FAO-56 equation 47
+    pub const UTAH_CHILL_UNITS: [(f64, f64); 6] = [
+        (1.5, 0.0),
+        (2.5, 0.5),
+        (9.2, 1.0),
+        (12.5, 0.5),
+        (16.0, 0.0),
+        (18.1, -0.5),
+    ]; // upper bound in °C and chill unit, warmer hours count -1
+    pub const DYNAMIC_MODEL_COEFS: [f64; 6] = [4153.5, 12888.8, 139500.0, 2.567e18, 1.6, 277.0]; // E0, E1, A0, A1, slope, transition temperature
+    pub const SOLAR_CONSTANT: f64 = 0.0820; // MJ/(m²·min), FAO-56
+    pub const STEFAN_BOLTZMANN_DAILY: f64 = 4.903e-9; // MJ/(K⁴·m²·day)
+    pub const LATENT_HEAT_OF_VAPORIZATION_MJ: f64 = 2.45; // MJ/kg at 20 °C, FAO-56
+    pub const GRASS_ALBEDO: f64 = 0.23; // hypothetical grass reference crop
+    pub const FAO_SATURATION_VAPOR_PRESSURE_COEFS: [f64; 3] = [0.6108, 17.27, 237.3]; // FAO-56 equation 11, kPa
+    pub const HARGREAVES_COEFS: [f64; 2] = [0.0023, 17.8];
+    pub const HARGREAVES_RADIATION_COEFFICIENT: f64 = 0.16; // kRs for interior locations
+    pub const PRIESTLEY_TAYLOR_ALPHA: f64 = 1.26;
+    pub const SECONDS_PER_DAY: i64 = 86400;
+    pub const JULIAN_DAY_UNIX_EPOCH: f64 = 2440587.5; // Julian day of 1 January 1970 00:00 UTC
+    pub const SOLAR_IRRADIANCE_CONSTANT: f64 = 1367.0; // W/m²
+    pub const ANGSTROM_COEFS: [f64; 2] = [0.25, 0.5]; // a, b, FAO-56 defaults
+    pub const HAURWITZ_COEFS: [f64; 2] = [1098.0, 0.059];
+    pub const INEICHEN_COEFS: [f64; 4] = [5.09e-5, 0.868, 3.92e-5, 0.0387]; // cg1 and cg2 as slope per meter and offset
+    pub const KASTEN_YOUNG_COEFS: [f64; 3] = [0.50572, 96.07995, 1.6364];
 }
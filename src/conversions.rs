@@ -1,3 +1,9 @@
+pub mod length;
+pub mod precipitation;
 pub mod pressure;
+pub mod quantity;
 pub mod temperature;
+pub mod unit_system;
+pub mod units;
+pub mod wind_direction;
 pub mod wind_speed;
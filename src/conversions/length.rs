@@ -0,0 +1,78 @@
+use crate::constants::constants::{
+    FEET_TO_METERS, INCHES_TO_MILLIMETERS, NAUTICAL_MILE_METERS, STATUTE_MILE_METERS,
+};
+use crate::{meteo_round, Float};
+
+/// Unit of length, depth or distance used for precipitation, snow depth and visibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LengthUnit {
+    /// Millimeter (mm).
+    Millimeter,
+    /// Centimeter (cm).
+    Centimeter,
+    /// Meter (m).
+    Meter,
+    /// Kilometer (km).
+    Kilometer,
+    /// Inch (in).
+    Inch,
+    /// Foot (ft).
+    Foot,
+    /// Statute mile (mi, SM).
+    StatuteMile,
+    /// Nautical mile (NM).
+    NauticalMile,
+}
+
+impl LengthUnit {
+    /// Returns how many meters one unit of this length unit is.
+    pub const fn meters(&self) -> f64 {
+        match self {
+            LengthUnit::Millimeter => 0.001,
+            LengthUnit::Centimeter => 0.01,
+            LengthUnit::Meter => 1.0,
+            LengthUnit::Kilometer => 1000.0,
+            LengthUnit::Inch => INCHES_TO_MILLIMETERS / 1000.0,
+            LengthUnit::Foot => FEET_TO_METERS,
+            LengthUnit::StatuteMile => STATUTE_MILE_METERS,
+            LengthUnit::NauticalMile => NAUTICAL_MILE_METERS,
+        }
+    }
+}
+
+/// Converts length between any two length units.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::LengthUnit;
+///
+/// let visibility = 10.0;
+/// let result = 16.0934;
+///
+/// let km = meteo_tools::convert_length(&visibility, LengthUnit::StatuteMile, LengthUnit::Kilometer);
+///
+/// assert_eq!(km, result);
+/// ```
+pub fn convert_length<F: Float>(length: &F, from: LengthUnit, to: LengthUnit) -> F {
+    let converted = *length * F::from_f64(from.meters() / to.meters());
+    meteo_round(&converted)
+}
+
+/// Converts length between any two length units, `const` variant of [`convert_length`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::LengthUnit;
+///
+/// const LIMIT: f64 = meteo_tools::const_convert_length(5.0, LengthUnit::Kilometer, LengthUnit::Meter);
+///
+/// assert_eq!(LIMIT, 5000.0);
+/// ```
+pub const fn const_convert_length(length: f64, from: LengthUnit, to: LengthUnit) -> f64 {
+    length * (from.meters() / to.meters())
+}
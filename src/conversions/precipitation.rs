@@ -0,0 +1,165 @@
+use crate::constants::constants::INCHES_TO_MILLIMETERS;
+use crate::{meteo_round, Float};
+
+/// Unit of precipitation rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrecipitationRateUnit {
+    /// Millimeters per hour (mm/h).
+    MillimetersPerHour,
+    /// Inches per hour (in/h).
+    InchesPerHour,
+    /// Kilograms per square meter per second (kg m⁻² s⁻¹), used by numerical models.
+    KilogramsPerSquareMeterPerSecond,
+}
+
+impl PrecipitationRateUnit {
+    /// Returns how many mm/h one unit of this precipitation rate unit is.
+    pub const fn millimeters_per_hour(&self) -> f64 {
+        match self {
+            PrecipitationRateUnit::MillimetersPerHour => 1.0,
+            PrecipitationRateUnit::InchesPerHour => INCHES_TO_MILLIMETERS,
+            // 1 kg of water spread over 1 m² is 1 mm deep
+            PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond => 3600.0,
+        }
+    }
+}
+
+/// Intensity class of precipitation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrecipitationIntensity {
+    /// Light precipitation.
+    Light,
+    /// Moderate precipitation.
+    Moderate,
+    /// Heavy precipitation.
+    Heavy,
+    /// Violent precipitation, used for rain only.
+    Violent,
+}
+
+/// Converts precipitation rate between any two precipitation rate units.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PrecipitationRateUnit;
+///
+/// let rate = 0.5;
+/// let result = 12.7;
+///
+/// let mmph = meteo_tools::convert_precipitation_rate(&rate, PrecipitationRateUnit::InchesPerHour, PrecipitationRateUnit::MillimetersPerHour);
+///
+/// assert_eq!(mmph, result);
+/// ```
+pub fn convert_precipitation_rate<F: Float>(
+    rate: &F,
+    from: PrecipitationRateUnit,
+    to: PrecipitationRateUnit,
+) -> F {
+    let converted = *rate * F::from_f64(from.millimeters_per_hour() / to.millimeters_per_hour());
+    meteo_round(&converted)
+}
+
+/// Classifies rain intensity from rain rate in mm/h by WMO thresholds
+/// (light < 2.5, moderate < 10, heavy < 50, violent from 50 mm/h).
+///
+/// Returns `None` when there is no rain or the rate is not finite
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PrecipitationIntensity;
+///
+/// let rate = 12.0;
+///
+/// let intensity = meteo_tools::rain_intensity(&rate);
+///
+/// assert_eq!(intensity, Some(PrecipitationIntensity::Heavy));
+/// assert_eq!(meteo_tools::rain_intensity(&f64::NAN), None);
+/// ```
+pub fn rain_intensity<F: Float>(rate_mmph: &F) -> Option<PrecipitationIntensity> {
+    match rate_mmph.to_f64() {
+        rate if !rate.is_finite() || rate <= 0.0 => None,
+        rate if rate < 2.5 => Some(PrecipitationIntensity::Light),
+        rate if rate < 10.0 => Some(PrecipitationIntensity::Moderate),
+        rate if rate < 50.0 => Some(PrecipitationIntensity::Heavy),
+        _ => Some(PrecipitationIntensity::Violent),
+    }
+}
+
+/// Classifies snow intensity from water equivalent rate in mm/h by WMO thresholds
+/// (light < 1, moderate < 5, heavy from 5 mm/h).
+///
+/// Returns `None` when there is no snowfall or the rate is not finite
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PrecipitationIntensity;
+///
+/// let rate = 1.2;
+///
+/// let intensity = meteo_tools::snow_intensity(&rate);
+///
+/// assert_eq!(intensity, Some(PrecipitationIntensity::Moderate));
+/// assert_eq!(meteo_tools::snow_intensity(&f64::NAN), None);
+/// ```
+pub fn snow_intensity<F: Float>(rate_mmph: &F) -> Option<PrecipitationIntensity> {
+    match rate_mmph.to_f64() {
+        rate if !rate.is_finite() || rate <= 0.0 => None,
+        rate if rate < 1.0 => Some(PrecipitationIntensity::Light),
+        rate if rate < 5.0 => Some(PrecipitationIntensity::Moderate),
+        _ => Some(PrecipitationIntensity::Heavy),
+    }
+}
+
+/// Classifies snow intensity from visibility in meters by AMS thresholds
+/// (light from 1000 m, moderate from 500 m, heavy below 500 m).
+///
+/// Returns `None` when the visibility is not finite
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PrecipitationIntensity;
+///
+/// let visibility = 400.0;
+///
+/// let intensity = meteo_tools::snow_intensity_from_visibility(&visibility);
+///
+/// assert_eq!(intensity, Some(PrecipitationIntensity::Heavy));
+/// assert_eq!(meteo_tools::snow_intensity_from_visibility(&f64::NAN), None);
+/// ```
+pub fn snow_intensity_from_visibility<F: Float>(
+    visibility_meters: &F,
+) -> Option<PrecipitationIntensity> {
+    match visibility_meters.to_f64() {
+        visibility if !visibility.is_finite() => None,
+        visibility if visibility >= 1000.0 => Some(PrecipitationIntensity::Light),
+        visibility if visibility >= 500.0 => Some(PrecipitationIntensity::Moderate),
+        _ => Some(PrecipitationIntensity::Heavy),
+    }
+}
+
+/// Converts precipitation rate between any two precipitation rate units, `const` variant of [`convert_precipitation_rate`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PrecipitationRateUnit;
+///
+/// const LIMIT: f64 = meteo_tools::const_convert_precipitation_rate(0.01, PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond, PrecipitationRateUnit::MillimetersPerHour);
+///
+/// assert_eq!(LIMIT, 36.0);
+/// ```
+pub const fn const_convert_precipitation_rate(
+    rate: f64,
+    from: PrecipitationRateUnit,
+    to: PrecipitationRateUnit,
+) -> f64 {
+    rate * (from.millimeters_per_hour() / to.millimeters_per_hour())
+}
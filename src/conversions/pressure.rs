@@ -1,5 +1,7 @@
-use crate::constants::constants::{INHG_CONVERSION, MMHG_CONVERSION};
-use crate::meteo_round;
+use crate::constants::constants::{
+    PASCALS_PER_ATM, PASCALS_PER_INHG, PASCALS_PER_MMHG, PASCALS_PER_PSI, PASCALS_PER_TORR,
+};
+use crate::{meteo_round, Float};
 
 /// Converts hPa to mmHg.
 ///
@@ -7,15 +9,18 @@ use crate::meteo_round;
 ///
 /// ```
 /// let pressure = 1013.25;
-/// let result = 760.0003;
+/// let result = 759.9999;
 ///
 /// let mmhg = meteo_tools::hpa_to_mmhg(&pressure);
 ///
 /// assert_eq!(mmhg, result);
 /// ```
-pub fn hpa_to_mmhg(hpa: &f64) -> f64 {
-    let mmhg = hpa * MMHG_CONVERSION;
-    meteo_round(&mmhg)
+pub fn hpa_to_mmhg<F: Float>(hpa: &F) -> F {
+    convert_pressure(
+        hpa,
+        PressureUnit::Hectopascal,
+        PressureUnit::MillimeterOfMercury,
+    )
 }
 
 /// Converts mmHg to hPa.
@@ -23,16 +28,19 @@ pub fn hpa_to_mmhg(hpa: &f64) -> f64 {
 /// # Examples
 ///
 /// ```
-/// let pressure = 760.0003;
-/// let result = 1013.25;
+/// let pressure = 760.0;
+/// let result = 1013.2501;
 ///
 /// let hpa = meteo_tools::mmhg_to_hpa(&pressure);
 ///
 /// assert_eq!(hpa, result);
 /// ```
-pub fn mmhg_to_hpa(mmhg: &f64) -> f64 {
-    let hpa = mmhg / MMHG_CONVERSION;
-    meteo_round(&hpa)
+pub fn mmhg_to_hpa<F: Float>(mmhg: &F) -> F {
+    convert_pressure(
+        mmhg,
+        PressureUnit::MillimeterOfMercury,
+        PressureUnit::Hectopascal,
+    )
 }
 
 /// Converts hPa to inHg.
@@ -41,15 +49,14 @@ pub fn mmhg_to_hpa(mmhg: &f64) -> f64 {
 ///
 /// ```
 /// let pressure = 1013.25;
-/// let result = 29.9212;
+/// let result = 29.9213;
 ///
 /// let inhg = meteo_tools::hpa_to_inhg(&pressure);
 ///
 /// assert_eq!(inhg, result);
 /// ```
-pub fn hpa_to_inhg(hpa: &f64) -> f64 {
-    let inhg = hpa / INHG_CONVERSION;
-    meteo_round(&inhg)
+pub fn hpa_to_inhg<F: Float>(hpa: &F) -> F {
+    convert_pressure(hpa, PressureUnit::Hectopascal, PressureUnit::InchOfMercury)
 }
 
 /// Converts inHg to hPa.
@@ -57,14 +64,160 @@ pub fn hpa_to_inhg(hpa: &f64) -> f64 {
 /// # Examples
 ///
 /// ```
-/// let pressure = 29.9212;
-/// let result = 1013.2485;
+/// let pressure = 29.92;
+/// let result = 1013.2076;
 ///
 /// let hpa = meteo_tools::inhg_to_hpa(&pressure);
 ///
 /// assert_eq!(hpa, result);
 /// ```
-pub fn inhg_to_hpa(inhg: &f64) -> f64 {
-    let hpa = inhg * INHG_CONVERSION;
-    meteo_round(&hpa)
+pub fn inhg_to_hpa<F: Float>(inhg: &F) -> F {
+    convert_pressure(inhg, PressureUnit::InchOfMercury, PressureUnit::Hectopascal)
+}
+
+/// Unit of atmospheric pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PressureUnit {
+    /// Pascal (Pa).
+    Pascal,
+    /// Hectopascal (hPa).
+    Hectopascal,
+    /// Kilopascal (kPa).
+    Kilopascal,
+    /// Bar (bar).
+    Bar,
+    /// Millibar (mbar), equal to hPa.
+    Millibar,
+    /// Millimeter of mercury (mmHg).
+    MillimeterOfMercury,
+    /// Inch of mercury (inHg).
+    InchOfMercury,
+    /// Pound-force per square inch (psi).
+    Psi,
+    /// Standard atmosphere (atm).
+    Atmosphere,
+    /// Torr, 1/760 of standard atmosphere.
+    Torr,
+}
+
+impl PressureUnit {
+    /// Returns how many Pascals one unit of this pressure unit is.
+    pub const fn pascals(&self) -> f64 {
+        match self {
+            PressureUnit::Pascal => 1.0,
+            PressureUnit::Hectopascal | PressureUnit::Millibar => 100.0,
+            PressureUnit::Kilopascal => 1000.0,
+            PressureUnit::Bar => 100000.0,
+            PressureUnit::MillimeterOfMercury => PASCALS_PER_MMHG,
+            PressureUnit::InchOfMercury => PASCALS_PER_INHG,
+            PressureUnit::Psi => PASCALS_PER_PSI,
+            PressureUnit::Atmosphere => PASCALS_PER_ATM,
+            PressureUnit::Torr => PASCALS_PER_TORR,
+        }
+    }
+}
+
+/// Converts pressure between any two pressure units.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PressureUnit;
+///
+/// let pressure = 1013.25;
+/// let result = 14.6959;
+///
+/// let psi = meteo_tools::convert_pressure(&pressure, PressureUnit::Hectopascal, PressureUnit::Psi);
+///
+/// assert_eq!(psi, result);
+/// ```
+pub fn convert_pressure<F: Float>(pressure: &F, from: PressureUnit, to: PressureUnit) -> F {
+    let converted = *pressure * F::from_f64(from.pascals() / to.pascals());
+    meteo_round(&converted)
+}
+
+/// Converts hPa to mmHg, `const` variant of [`hpa_to_mmhg`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_hpa_to_mmhg(1000.0);
+///
+/// assert!((LIMIT - 750.0616).abs() < 1e-4);
+/// ```
+pub const fn const_hpa_to_mmhg(hpa: f64) -> f64 {
+    const_convert_pressure(
+        hpa,
+        PressureUnit::Hectopascal,
+        PressureUnit::MillimeterOfMercury,
+    )
+}
+
+/// Converts mmHg to hPa, `const` variant of [`mmhg_to_hpa`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mmhg_to_hpa(750.0616);
+///
+/// assert!((LIMIT - 1000.0).abs() < 1e-4);
+/// ```
+pub const fn const_mmhg_to_hpa(mmhg: f64) -> f64 {
+    const_convert_pressure(
+        mmhg,
+        PressureUnit::MillimeterOfMercury,
+        PressureUnit::Hectopascal,
+    )
+}
+
+/// Converts hPa to inHg, `const` variant of [`hpa_to_inhg`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_hpa_to_inhg(338.6389);
+///
+/// assert!((LIMIT - 10.0).abs() < 1e-4);
+/// ```
+pub const fn const_hpa_to_inhg(hpa: f64) -> f64 {
+    const_convert_pressure(hpa, PressureUnit::Hectopascal, PressureUnit::InchOfMercury)
+}
+
+/// Converts inHg to hPa, `const` variant of [`inhg_to_hpa`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_inhg_to_hpa(30.0);
+///
+/// assert_eq!(LIMIT, 1015.9167);
+/// ```
+pub const fn const_inhg_to_hpa(inhg: f64) -> f64 {
+    const_convert_pressure(inhg, PressureUnit::InchOfMercury, PressureUnit::Hectopascal)
+}
+
+/// Converts pressure between any two pressure units, `const` variant of [`convert_pressure`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::PressureUnit;
+///
+/// const LIMIT: f64 = meteo_tools::const_convert_pressure(1.0, PressureUnit::Bar, PressureUnit::Hectopascal);
+///
+/// assert_eq!(LIMIT, 1000.0);
+/// ```
+pub const fn const_convert_pressure(pressure: f64, from: PressureUnit, to: PressureUnit) -> f64 {
+    pressure * (from.pascals() / to.pascals())
 }
@@ -0,0 +1,219 @@
+use core::fmt;
+use core::fmt::Write;
+use core::str::FromStr;
+
+use crate::{convert_unit, Unit, UnitError};
+
+/// Value together with its unit, such as `22.5 °C` or `29.92 inHg`.
+///
+/// Quantities are parsed with [`FromStr`] and printed with [`fmt::Display`], where the precision
+/// of the formatter is applied to the value, so `format!("{:.1}", quantity)` prints one decimal place.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{Quantity, SpeedUnit, TemperatureScale, Unit};
+///
+/// let temperature: Quantity = "22,5 °C".parse().unwrap();
+/// let fahrenheit = temperature.convert_to(Unit::Temperature(TemperatureScale::Fahrenheit)).unwrap();
+///
+/// assert_eq!(fahrenheit.to_string(), "72.5 °F");
+///
+/// let speed = Quantity::new(0.8, Unit::Speed(SpeedUnit::Mach(-20.0)));
+///
+/// assert_eq!(speed.to_string(), "0.8 Mach(-20)");
+/// assert_eq!(speed.to_string().parse(), Ok(speed));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quantity {
+    /// Numeric value in `unit`.
+    pub value: f64,
+    /// Unit of the value.
+    pub unit: Unit,
+}
+
+/// Formatting options for [`Quantity::display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantityFormat {
+    /// Number of decimal places, `None` prints the value as short as possible.
+    pub precision: Option<usize>,
+    /// Character separating the decimal part, `.` or `,` for most locales.
+    pub decimal_separator: char,
+    /// Whether to put a space between the value and the unit symbol.
+    pub space_before_unit: bool,
+}
+
+impl Default for QuantityFormat {
+    fn default() -> Self {
+        QuantityFormat {
+            precision: None,
+            decimal_separator: '.',
+            space_before_unit: true,
+        }
+    }
+}
+
+/// Error returned when a quantity cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseQuantityError {
+    /// Numeric part is missing or is not a number.
+    InvalidValue,
+    /// Unit part is missing or not recognised.
+    Unit(UnitError),
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseQuantityError::InvalidValue => f.write_str("invalid quantity value"),
+            ParseQuantityError::Unit(error) => write!(f, "invalid quantity unit: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseQuantityError {}
+
+impl From<UnitError> for ParseQuantityError {
+    fn from(error: UnitError) -> Self {
+        ParseQuantityError::Unit(error)
+    }
+}
+
+impl Quantity {
+    /// Creates quantity from value and unit.
+    pub fn new(value: f64, unit: Unit) -> Self {
+        Quantity { value, unit }
+    }
+
+    /// Converts quantity to another unit of the same dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{PressureUnit, Quantity, Unit};
+    ///
+    /// let pressure = Quantity::new(29.92, Unit::Pressure(PressureUnit::InchOfMercury));
+    /// let result = Quantity::new(1013.2076, Unit::Pressure(PressureUnit::Hectopascal));
+    ///
+    /// let hpa = pressure.convert_to(Unit::Pressure(PressureUnit::Hectopascal));
+    ///
+    /// assert_eq!(hpa, Ok(result));
+    /// ```
+    pub fn convert_to(&self, unit: Unit) -> Result<Quantity, UnitError> {
+        let value = convert_unit(&self.value, self.unit, unit)?;
+        Ok(Quantity { value, unit })
+    }
+
+    /// Returns displayable quantity using given format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{PressureUnit, Quantity, QuantityFormat, Unit};
+    ///
+    /// let pressure = Quantity::new(1013.27, Unit::Pressure(PressureUnit::Hectopascal));
+    /// let format = QuantityFormat { precision: Some(1), decimal_separator: ',', ..Default::default() };
+    ///
+    /// assert_eq!(pressure.display(format).to_string(), "1013,3 hPa");
+    /// ```
+    pub fn display(&self, format: QuantityFormat) -> QuantityDisplay<'_> {
+        QuantityDisplay {
+            quantity: self,
+            format,
+        }
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = QuantityFormat {
+            precision: f.precision(),
+            ..Default::default()
+        };
+        fmt::Display::fmt(&self.display(format), f)
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = ParseQuantityError;
+
+    /// Parses value followed by unit symbol. Decimal comma is accepted as well as decimal point,
+    /// the space between value and unit is optional.
+    fn from_str(quantity: &str) -> Result<Self, Self::Err> {
+        let quantity = quantity.trim();
+        let unit_start = quantity
+            .char_indices()
+            .find(|(index, character)| {
+                !(character.is_ascii_digit()
+                    || *character == '.'
+                    || *character == ','
+                    || (*index == 0 && (*character == '-' || *character == '+')))
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(quantity.len());
+        let (value, unit) = quantity.split_at(unit_start);
+        let value = parse_value(value).ok_or(ParseQuantityError::InvalidValue)?;
+        let unit = unit.parse()?;
+        Ok(Quantity { value, unit })
+    }
+}
+
+// Parses number with either decimal point or decimal comma
+fn parse_value(value: &str) -> Option<f64> {
+    if value.contains('.') && value.contains(',') {
+        return None;
+    }
+    let mut buffer = [0u8; 64];
+    let bytes = value.as_bytes();
+    let normalized = buffer.get_mut(..bytes.len())?;
+    for (target, source) in normalized.iter_mut().zip(bytes) {
+        *target = if *source == b',' { b'.' } else { *source };
+    }
+    core::str::from_utf8(normalized).ok()?.parse().ok()
+}
+
+/// Quantity formatted by [`Quantity::display`].
+pub struct QuantityDisplay<'a> {
+    quantity: &'a Quantity,
+    format: QuantityFormat,
+}
+
+impl fmt::Display for QuantityDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = DecimalSeparatorWriter {
+            inner: f,
+            separator: self.format.decimal_separator,
+        };
+        match self.format.precision {
+            Some(precision) => write!(writer, "{:.*}", precision, self.quantity.value)?,
+            None => write!(writer, "{}", self.quantity.value)?,
+        }
+        if self.format.space_before_unit {
+            f.write_char(' ')?;
+        }
+        write!(f, "{}", self.quantity.unit)
+    }
+}
+
+// Replaces decimal point of formatted number with configured separator
+struct DecimalSeparatorWriter<'a, 'b> {
+    inner: &'a mut fmt::Formatter<'b>,
+    separator: char,
+}
+
+impl Write for DecimalSeparatorWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for part in s.split_inclusive('.') {
+            match part.strip_suffix('.') {
+                Some(number) => {
+                    self.inner.write_str(number)?;
+                    self.inner.write_char(self.separator)?;
+                }
+                None => self.inner.write_str(part)?,
+            }
+        }
+        Ok(())
+    }
+}
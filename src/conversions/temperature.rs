@@ -1,4 +1,5 @@
 use crate::constants::constants::KELVIN_DEGREE_CONSTANT;
+use crate::{meteo_round, Float};
 
 /// Converts celsius to fahrenheit degrees
 ///
@@ -12,8 +13,8 @@ use crate::constants::constants::KELVIN_DEGREE_CONSTANT;
 ///
 /// assert_eq!(fahrenheit, result);
 /// ```
-pub fn celsius_to_fahrenheit(celsius: &f64) -> f64 {
-    (celsius * 9.0 / 5.0) + 32.0
+pub fn celsius_to_fahrenheit<F: Float>(celsius: &F) -> F {
+    (*celsius * F::from_f64(9.0) / F::from_f64(5.0)) + F::from_f64(32.0)
 }
 
 /// Converts fahrenheit to celsius degrees
@@ -28,8 +29,8 @@ pub fn celsius_to_fahrenheit(celsius: &f64) -> f64 {
 ///
 /// assert_eq!(celsius, result);
 /// ```
-pub fn fahrenheit_to_celsius(fahrenheit: &f64) -> f64 {
-    (fahrenheit - 32.0) * 5.0 / 9.0
+pub fn fahrenheit_to_celsius<F: Float>(fahrenheit: &F) -> F {
+    (*fahrenheit - F::from_f64(32.0)) * F::from_f64(5.0) / F::from_f64(9.0)
 }
 
 /// Converts Celsius to Kelvin
@@ -44,8 +45,8 @@ pub fn fahrenheit_to_celsius(fahrenheit: &f64) -> f64 {
 ///
 /// assert_eq!(kelvin, result);
 /// ```
-pub fn celsius_to_kelvin(celsius: &f64) -> f64 {
-    celsius + KELVIN_DEGREE_CONSTANT
+pub fn celsius_to_kelvin<F: Float>(celsius: &F) -> F {
+    *celsius + F::from_f64(KELVIN_DEGREE_CONSTANT)
 }
 
 /// Converts Kelvin to Celsius
@@ -60,8 +61,8 @@ pub fn celsius_to_kelvin(celsius: &f64) -> f64 {
 ///
 /// assert_eq!(celsius, result);
 /// ```
-pub fn kelvin_to_celsius(kelvin: &f64) -> f64 {
-    kelvin - KELVIN_DEGREE_CONSTANT
+pub fn kelvin_to_celsius<F: Float>(kelvin: &F) -> F {
+    *kelvin - F::from_f64(KELVIN_DEGREE_CONSTANT)
 }
 
 /// Converts Fahrenheit to Kelvin
@@ -76,9 +77,9 @@ pub fn kelvin_to_celsius(kelvin: &f64) -> f64 {
 ///
 /// assert_eq!(kelvin, result);
 /// ```
-pub fn fahrenheit_to_kelvin(fahrenheit: &f64) -> f64 {
+pub fn fahrenheit_to_kelvin<F: Float>(fahrenheit: &F) -> F {
     let temperature_celsius = fahrenheit_to_celsius(fahrenheit);
-    temperature_celsius + KELVIN_DEGREE_CONSTANT
+    temperature_celsius + F::from_f64(KELVIN_DEGREE_CONSTANT)
 }
 
 /// Converts Kelvin to Celsius
@@ -93,7 +94,242 @@ pub fn fahrenheit_to_kelvin(fahrenheit: &f64) -> f64 {
 ///
 /// assert_eq!(fahrenheit, result);
 /// ```
-pub fn kelvin_to_fahrenheit(kelvin: &f64) -> f64 {
-    let temperature_celsius = kelvin - KELVIN_DEGREE_CONSTANT;
+pub fn kelvin_to_fahrenheit<F: Float>(kelvin: &F) -> F {
+    let temperature_celsius = *kelvin - F::from_f64(KELVIN_DEGREE_CONSTANT);
     celsius_to_fahrenheit(&temperature_celsius)
 }
+
+/// Temperature scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemperatureScale {
+    /// Degrees Celsius (°C).
+    Celsius,
+    /// Degrees Fahrenheit (°F).
+    Fahrenheit,
+    /// Kelvin (K).
+    Kelvin,
+    /// Degrees Rankine (°R), absolute scale with Fahrenheit sized degrees.
+    Rankine,
+    /// Degrees Réaumur (°Ré), water freezes at 0 and boils at 80.
+    Reaumur,
+    /// Degrees Delisle (°De), inverted scale, water boils at 0 and freezes at 150.
+    Delisle,
+    /// Degrees Newton (°N), water freezes at 0 and boils at 33.
+    Newton,
+    /// Degrees Rømer (°Rø), water freezes at 7.5 and boils at 60.
+    Romer,
+}
+
+impl TemperatureScale {
+    /// Number of degrees of this scale per one Celsius degree.
+    /// Negative for Delisle, because its values grow as temperature falls.
+    const fn degrees_per_celsius(&self) -> f64 {
+        match self {
+            TemperatureScale::Celsius | TemperatureScale::Kelvin => 1.0,
+            TemperatureScale::Fahrenheit | TemperatureScale::Rankine => 9.0 / 5.0,
+            TemperatureScale::Reaumur => 4.0 / 5.0,
+            TemperatureScale::Delisle => -3.0 / 2.0,
+            TemperatureScale::Newton => 33.0 / 100.0,
+            TemperatureScale::Romer => 21.0 / 40.0,
+        }
+    }
+
+    // Value of this scale at 0 °C
+    const fn celsius_zero(&self) -> f64 {
+        match self {
+            TemperatureScale::Celsius | TemperatureScale::Reaumur | TemperatureScale::Newton => 0.0,
+            TemperatureScale::Fahrenheit => 32.0,
+            TemperatureScale::Kelvin => KELVIN_DEGREE_CONSTANT,
+            TemperatureScale::Rankine => KELVIN_DEGREE_CONSTANT * 9.0 / 5.0,
+            TemperatureScale::Delisle => 150.0,
+            TemperatureScale::Romer => 7.5,
+        }
+    }
+}
+
+/// Converts temperature between any two temperature scales.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// let temperature = 22.5;
+/// let result = 532.17;
+///
+/// let rankine = meteo_tools::convert_temperature(&temperature, TemperatureScale::Celsius, TemperatureScale::Rankine);
+///
+/// assert_eq!(rankine, result);
+/// ```
+pub fn convert_temperature<F: Float>(
+    temperature: &F,
+    from: TemperatureScale,
+    to: TemperatureScale,
+) -> F {
+    let celsius =
+        (*temperature - F::from_f64(from.celsius_zero())) / F::from_f64(from.degrees_per_celsius());
+    let converted =
+        celsius * F::from_f64(to.degrees_per_celsius()) + F::from_f64(to.celsius_zero());
+    meteo_round(&converted)
+}
+
+/// Converts temperature difference between any two temperature scales.
+///
+/// Unlike [`convert_temperature`] it ignores the offset of the scales, so a difference of 1 °C is 1.8 °F.
+/// Differences in Delisle have opposite sign, because Delisle values grow as temperature falls.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// let difference = 1.0;
+/// let result = 1.8;
+///
+/// let fahrenheit = meteo_tools::convert_temperature_difference(&difference, TemperatureScale::Celsius, TemperatureScale::Fahrenheit);
+///
+/// assert_eq!(fahrenheit, result);
+/// ```
+pub fn convert_temperature_difference<F: Float>(
+    difference: &F,
+    from: TemperatureScale,
+    to: TemperatureScale,
+) -> F {
+    let converted =
+        *difference * F::from_f64(to.degrees_per_celsius() / from.degrees_per_celsius());
+    meteo_round(&converted)
+}
+
+/// Converts celsius to fahrenheit degrees, `const` variant of [`celsius_to_fahrenheit`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const ALARM_LIMIT: f64 = meteo_tools::const_celsius_to_fahrenheit(35.0);
+///
+/// assert_eq!(ALARM_LIMIT, 95.0);
+/// ```
+pub const fn const_celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// Converts fahrenheit to celsius degrees, `const` variant of [`fahrenheit_to_celsius`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const ALARM_LIMIT: f64 = meteo_tools::const_fahrenheit_to_celsius(95.0);
+///
+/// assert_eq!(ALARM_LIMIT, 35.0);
+/// ```
+pub const fn const_fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+/// Converts Celsius to Kelvin, `const` variant of [`celsius_to_kelvin`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_celsius_to_kelvin(22.5);
+///
+/// assert_eq!(LIMIT, 295.65);
+/// ```
+pub const fn const_celsius_to_kelvin(celsius: f64) -> f64 {
+    celsius + KELVIN_DEGREE_CONSTANT
+}
+
+/// Converts Kelvin to Celsius, `const` variant of [`kelvin_to_celsius`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_kelvin_to_celsius(295.65);
+///
+/// assert_eq!(LIMIT, 22.5);
+/// ```
+pub const fn const_kelvin_to_celsius(kelvin: f64) -> f64 {
+    kelvin - KELVIN_DEGREE_CONSTANT
+}
+
+/// Converts Fahrenheit to Kelvin, `const` variant of [`fahrenheit_to_kelvin`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_fahrenheit_to_kelvin(32.0);
+///
+/// assert_eq!(LIMIT, 273.15);
+/// ```
+pub const fn const_fahrenheit_to_kelvin(fahrenheit: f64) -> f64 {
+    const_fahrenheit_to_celsius(fahrenheit) + KELVIN_DEGREE_CONSTANT
+}
+
+/// Converts Kelvin to Fahrenheit, `const` variant of [`kelvin_to_fahrenheit`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_kelvin_to_fahrenheit(273.15);
+///
+/// assert_eq!(LIMIT, 32.0);
+/// ```
+pub const fn const_kelvin_to_fahrenheit(kelvin: f64) -> f64 {
+    const_celsius_to_fahrenheit(kelvin - KELVIN_DEGREE_CONSTANT)
+}
+
+/// Converts temperature between any two temperature scales, `const` variant of [`convert_temperature`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// const LIMIT: f64 = meteo_tools::const_convert_temperature(-40.0, TemperatureScale::Celsius, TemperatureScale::Fahrenheit);
+///
+/// assert_eq!(LIMIT, -40.0);
+/// ```
+pub const fn const_convert_temperature(
+    temperature: f64,
+    from: TemperatureScale,
+    to: TemperatureScale,
+) -> f64 {
+    let celsius = (temperature - from.celsius_zero()) / from.degrees_per_celsius();
+    celsius * to.degrees_per_celsius() + to.celsius_zero()
+}
+
+/// Converts temperature difference between any two temperature scales, `const` variant of [`convert_temperature_difference`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::TemperatureScale;
+///
+/// const MAX_DROP: f64 = meteo_tools::const_convert_temperature_difference(5.0, TemperatureScale::Celsius, TemperatureScale::Fahrenheit);
+///
+/// assert_eq!(MAX_DROP, 9.0);
+/// ```
+pub const fn const_convert_temperature_difference(
+    difference: f64,
+    from: TemperatureScale,
+    to: TemperatureScale,
+) -> f64 {
+    difference * (to.degrees_per_celsius() / from.degrees_per_celsius())
+}
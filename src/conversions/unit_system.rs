@@ -0,0 +1,272 @@
+use crate::{
+    convert_length, convert_pressure, convert_speed, convert_temperature, DerivedQuantities,
+    Dimension, LengthUnit, Observation, PrecipitationRateUnit, PressureUnit, Quantity, SpeedUnit,
+    TemperatureScale, Unit, WindDirection,
+};
+
+/// Preferred units for presenting whole observations, one unit per physical dimension.
+///
+/// | System       | Temperature | Pressure | Speed | Visibility     | Height | Precipitation |
+/// |--------------|-------------|----------|-------|----------------|--------|---------------|
+/// | Metric       | °C          | hPa      | km/h  | km             | m      | mm/h          |
+/// | Imperial     | °F          | inHg     | mph   | statute miles  | ft     | in/h          |
+/// | Si           | K           | Pa       | m/s   | m              | m      | kg/m²/s       |
+/// | Aviation     | °C          | hPa      | kt    | statute miles  | ft     | mm/h          |
+/// | AviationInHg | °C          | inHg     | kt    | statute miles  | ft     | mm/h          |
+/// | Marine       | °C          | hPa      | kt    | nautical miles | m      | mm/h          |
+///
+/// Aviation follows ICAO units with visibility in statute miles as in North American METARs,
+/// AviationInHg reports the altimeter setting in inHg as in the United States and Canada.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{Observation, UnitSystem, WindDirection};
+///
+/// let observation = Observation::new(22.5, 62.4)
+///     .with_pressure(1013.25)
+///     .with_wind(5.0, WindDirection::Degrees(240.0));
+///
+/// let imperial = UnitSystem::Imperial.convert_observation(&observation);
+///
+/// assert_eq!(imperial.temperature.to_string(), "72.5 °F");
+/// assert_eq!(imperial.pressure.unwrap().to_string(), "29.9213 inHg");
+/// assert_eq!(imperial.wind_speed.unwrap().to_string(), "11.1847 mph");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnitSystem {
+    /// Metric units of weather services, wind speed in km/h.
+    Metric,
+    /// United States customary units.
+    Imperial,
+    /// SI base and derived units.
+    Si,
+    /// ICAO aviation units with pressure in hPa.
+    Aviation,
+    /// Aviation units with the altimeter setting in inHg.
+    AviationInHg,
+    /// Marine units, wind speed in knots and visibility in nautical miles.
+    Marine,
+}
+
+/// [`Observation`] converted to the units of a [`UnitSystem`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct ConvertedObservation {
+    /// Air temperature in the preferred temperature scale.
+    pub temperature: Quantity,
+    /// Relative humidity in percent.
+    pub relative_humidity: f64,
+    /// Station atmospheric pressure in the preferred pressure unit.
+    pub pressure: Option<Quantity>,
+    /// Wind speed in the preferred speed unit.
+    pub wind_speed: Option<Quantity>,
+    /// Direction the wind blows from, not converted.
+    pub wind_direction: Option<WindDirection>,
+    /// Global solar radiation in W/m².
+    pub solar_radiation: Option<f64>,
+    /// Horizontal visibility in the preferred length unit.
+    pub visibility: Option<Quantity>,
+}
+
+/// [`DerivedQuantities`] converted to the units of a [`UnitSystem`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct ConvertedDerivedQuantities {
+    /// Saturation vapor pressure in the preferred pressure unit.
+    pub saturation_vapor_pressure: Quantity,
+    /// Actual vapor pressure in the preferred pressure unit.
+    pub vapor_pressure: Quantity,
+    /// Dew point in the preferred temperature scale.
+    pub dew_point: Quantity,
+    /// Heat index in the preferred temperature scale.
+    pub heat_index: Quantity,
+    /// Humidex is a dimensionless index and is never converted.
+    pub humidex: f64,
+    /// Mixing ratio in kg/kg.
+    pub mixing_ratio: f64,
+    /// Absolute humidity in kg/m³.
+    pub absolute_humidity: f64,
+    /// Beaufort force of the wind.
+    pub beaufort: Option<f64>,
+}
+
+impl UnitSystem {
+    /// Returns preferred temperature scale.
+    pub fn temperature_scale(&self) -> TemperatureScale {
+        match self {
+            UnitSystem::Imperial => TemperatureScale::Fahrenheit,
+            UnitSystem::Si => TemperatureScale::Kelvin,
+            UnitSystem::Metric
+            | UnitSystem::Aviation
+            | UnitSystem::AviationInHg
+            | UnitSystem::Marine => TemperatureScale::Celsius,
+        }
+    }
+
+    /// Returns preferred pressure unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{PressureUnit, UnitSystem};
+    ///
+    /// assert_eq!(UnitSystem::Aviation.pressure_unit(), PressureUnit::Hectopascal);
+    /// assert_eq!(UnitSystem::AviationInHg.pressure_unit(), PressureUnit::InchOfMercury);
+    /// ```
+    pub fn pressure_unit(&self) -> PressureUnit {
+        match self {
+            UnitSystem::Imperial | UnitSystem::AviationInHg => PressureUnit::InchOfMercury,
+            UnitSystem::Si => PressureUnit::Pascal,
+            UnitSystem::Metric | UnitSystem::Aviation | UnitSystem::Marine => {
+                PressureUnit::Hectopascal
+            }
+        }
+    }
+
+    /// Returns preferred speed unit.
+    pub fn speed_unit(&self) -> SpeedUnit {
+        match self {
+            UnitSystem::Metric => SpeedUnit::KilometersPerHour,
+            UnitSystem::Imperial => SpeedUnit::MilesPerHour,
+            UnitSystem::Si => SpeedUnit::MetersPerSecond,
+            UnitSystem::Aviation | UnitSystem::AviationInHg | UnitSystem::Marine => {
+                SpeedUnit::Knots
+            }
+        }
+    }
+
+    /// Returns preferred unit of horizontal distances such as visibility.
+    pub fn length_unit(&self) -> LengthUnit {
+        match self {
+            UnitSystem::Metric => LengthUnit::Kilometer,
+            UnitSystem::Imperial | UnitSystem::Aviation | UnitSystem::AviationInHg => {
+                LengthUnit::StatuteMile
+            }
+            UnitSystem::Si => LengthUnit::Meter,
+            UnitSystem::Marine => LengthUnit::NauticalMile,
+        }
+    }
+
+    /// Returns preferred unit of heights such as cloud base or elevation.
+    pub fn height_unit(&self) -> LengthUnit {
+        match self {
+            UnitSystem::Imperial | UnitSystem::Aviation | UnitSystem::AviationInHg => {
+                LengthUnit::Foot
+            }
+            UnitSystem::Metric | UnitSystem::Si | UnitSystem::Marine => LengthUnit::Meter,
+        }
+    }
+
+    /// Returns preferred precipitation rate unit.
+    pub fn precipitation_rate_unit(&self) -> PrecipitationRateUnit {
+        match self {
+            UnitSystem::Imperial => PrecipitationRateUnit::InchesPerHour,
+            UnitSystem::Si => PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond,
+            UnitSystem::Metric
+            | UnitSystem::Aviation
+            | UnitSystem::AviationInHg
+            | UnitSystem::Marine => PrecipitationRateUnit::MillimetersPerHour,
+        }
+    }
+
+    /// Returns preferred unit of the dimension. Lengths use the unit of horizontal distances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{Dimension, SpeedUnit, Unit, UnitSystem};
+    ///
+    /// assert_eq!(UnitSystem::Marine.unit(Dimension::Speed), Unit::Speed(SpeedUnit::Knots));
+    /// ```
+    pub fn unit(&self, dimension: Dimension) -> Unit {
+        match dimension {
+            Dimension::Temperature => Unit::Temperature(self.temperature_scale()),
+            Dimension::Pressure => Unit::Pressure(self.pressure_unit()),
+            Dimension::Speed => Unit::Speed(self.speed_unit()),
+            Dimension::Length => Unit::Length(self.length_unit()),
+            Dimension::PrecipitationRate => Unit::PrecipitationRate(self.precipitation_rate_unit()),
+        }
+    }
+
+    /// Converts quantity to the preferred unit of its dimension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{Quantity, UnitSystem};
+    ///
+    /// let speed: Quantity = "10 m/s".parse().unwrap();
+    ///
+    /// let result = UnitSystem::Metric.convert(&speed);
+    ///
+    /// assert_eq!(result.to_string(), "36 km/h");
+    /// ```
+    pub fn convert(&self, quantity: &Quantity) -> Quantity {
+        quantity
+            .convert_to(self.unit(quantity.unit.dimension()))
+            .unwrap_or(*quantity)
+    }
+
+    /// Converts all measurements of the observation to preferred units.
+    pub fn convert_observation(&self, observation: &Observation) -> ConvertedObservation {
+        ConvertedObservation {
+            temperature: self.temperature(observation.temperature),
+            relative_humidity: observation.relative_humidity,
+            pressure: observation.pressure.map(|pressure| self.pressure(pressure)),
+            wind_speed: observation.wind_speed.map(|speed| {
+                let unit = self.speed_unit();
+                let speed = convert_speed(&speed, SpeedUnit::MetersPerSecond, unit);
+                Quantity::new(speed, Unit::Speed(unit))
+            }),
+            wind_direction: observation.wind_direction,
+            solar_radiation: observation.solar_radiation,
+            visibility: observation.visibility.map(|visibility| {
+                let unit = self.length_unit();
+                let visibility = convert_length(&visibility, LengthUnit::Meter, unit);
+                Quantity::new(visibility, Unit::Length(unit))
+            }),
+        }
+    }
+
+    /// Converts all derived quantities to preferred units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{Observation, UnitSystem};
+    ///
+    /// let derived = Observation::new(22.5, 62.4).derived();
+    ///
+    /// let result = UnitSystem::Si.convert_derived(&derived);
+    ///
+    /// assert_eq!(result.dew_point.to_string(), "288.0977 K");
+    /// ```
+    pub fn convert_derived(&self, derived: &DerivedQuantities) -> ConvertedDerivedQuantities {
+        ConvertedDerivedQuantities {
+            saturation_vapor_pressure: self.pressure(derived.saturation_vapor_pressure),
+            vapor_pressure: self.pressure(derived.vapor_pressure),
+            dew_point: self.temperature(derived.dew_point),
+            heat_index: self.temperature(derived.heat_index),
+            humidex: derived.humidex,
+            mixing_ratio: derived.mixing_ratio,
+            absolute_humidity: derived.absolute_humidity,
+            beaufort: derived.beaufort,
+        }
+    }
+
+    fn temperature(&self, celsius: f64) -> Quantity {
+        let scale = self.temperature_scale();
+        let temperature = convert_temperature(&celsius, TemperatureScale::Celsius, scale);
+        Quantity::new(temperature, Unit::Temperature(scale))
+    }
+
+    fn pressure(&self, hectopascals: f64) -> Quantity {
+        let unit = self.pressure_unit();
+        let pressure = convert_pressure(&hectopascals, PressureUnit::Hectopascal, unit);
+        Quantity::new(pressure, Unit::Pressure(unit))
+    }
+}
@@ -0,0 +1,535 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{
+    convert_length, convert_precipitation_rate, convert_pressure, convert_speed,
+    convert_temperature, Float, LengthUnit, PrecipitationRateUnit, PressureUnit, SpeedUnit,
+    TemperatureScale,
+};
+
+/// Physical dimension of a unit. Only units of the same dimension can be converted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dimension {
+    /// Temperature, see [`TemperatureScale`].
+    Temperature,
+    /// Pressure, see [`PressureUnit`].
+    Pressure,
+    /// Speed, see [`SpeedUnit`].
+    Speed,
+    /// Length and distance, see [`LengthUnit`].
+    Length,
+    /// Precipitation rate, see [`PrecipitationRateUnit`].
+    PrecipitationRate,
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Dimension::Temperature => "temperature",
+            Dimension::Pressure => "pressure",
+            Dimension::Speed => "speed",
+            Dimension::Length => "length",
+            Dimension::PrecipitationRate => "precipitation rate",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Any unit the crate can convert, resolved at runtime from its symbol.
+///
+/// Symbols are parsed with [`FromStr`], which accepts common symbols (`hPa`, `°F`, `kt`),
+/// UDUNITS and CF convention spellings (`degC`, `degree_Celsius`, `m s-1`, `kg m-2 s-1`) and names (`knots`, `millibar`).
+/// Exact spelling is tried first, then case-insensitive match of names and symbols which cannot be
+/// mistaken for another unit, so `KT` and `MPH` are accepted as well, while `nm`, `Mm` or `c` are not.
+/// `Mach` resolves to Mach number at 15 °C of the standard atmosphere, `Mach(-20)` to Mach number at -20 °C.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{LengthUnit, SpeedUnit, Unit};
+///
+/// assert_eq!("KT".parse(), Ok(Unit::Speed(SpeedUnit::Knots)));
+/// assert_eq!("NM".parse(), Ok(Unit::Length(LengthUnit::NauticalMile)));
+/// assert!("nm".parse::<Unit>().is_err());
+/// assert!("Mm".parse::<Unit>().is_err());
+/// assert!("c".parse::<Unit>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    /// Temperature scale.
+    Temperature(TemperatureScale),
+    /// Pressure unit.
+    Pressure(PressureUnit),
+    /// Speed unit, including Mach number and Beaufort force.
+    Speed(SpeedUnit),
+    /// Length, depth or visibility unit.
+    Length(LengthUnit),
+    /// Precipitation rate unit.
+    PrecipitationRate(PrecipitationRateUnit),
+}
+
+// Air temperature of ICAO standard atmosphere at sea level
+const STANDARD_ATMOSPHERE_TEMPERATURE: f64 = 15.0;
+
+const UNIT_SYMBOLS: &[(&str, Unit)] = &[
+    // temperature
+    ("°C", Unit::Temperature(TemperatureScale::Celsius)),
+    ("℃", Unit::Temperature(TemperatureScale::Celsius)),
+    ("C", Unit::Temperature(TemperatureScale::Celsius)),
+    ("degC", Unit::Temperature(TemperatureScale::Celsius)),
+    ("deg_C", Unit::Temperature(TemperatureScale::Celsius)),
+    ("degree_C", Unit::Temperature(TemperatureScale::Celsius)),
+    ("degrees_C", Unit::Temperature(TemperatureScale::Celsius)),
+    (
+        "degree_Celsius",
+        Unit::Temperature(TemperatureScale::Celsius),
+    ),
+    (
+        "degrees_Celsius",
+        Unit::Temperature(TemperatureScale::Celsius),
+    ),
+    ("celsius", Unit::Temperature(TemperatureScale::Celsius)),
+    ("°F", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    ("℉", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    ("F", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    ("degF", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    ("deg_F", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    ("degree_F", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    ("degrees_F", Unit::Temperature(TemperatureScale::Fahrenheit)),
+    (
+        "degree_Fahrenheit",
+        Unit::Temperature(TemperatureScale::Fahrenheit),
+    ),
+    (
+        "fahrenheit",
+        Unit::Temperature(TemperatureScale::Fahrenheit),
+    ),
+    ("K", Unit::Temperature(TemperatureScale::Kelvin)),
+    ("kelvin", Unit::Temperature(TemperatureScale::Kelvin)),
+    ("degK", Unit::Temperature(TemperatureScale::Kelvin)),
+    ("°R", Unit::Temperature(TemperatureScale::Rankine)),
+    ("degR", Unit::Temperature(TemperatureScale::Rankine)),
+    ("rankine", Unit::Temperature(TemperatureScale::Rankine)),
+    ("°Ré", Unit::Temperature(TemperatureScale::Reaumur)),
+    ("°Re", Unit::Temperature(TemperatureScale::Reaumur)),
+    ("reaumur", Unit::Temperature(TemperatureScale::Reaumur)),
+    ("°De", Unit::Temperature(TemperatureScale::Delisle)),
+    ("delisle", Unit::Temperature(TemperatureScale::Delisle)),
+    ("°N", Unit::Temperature(TemperatureScale::Newton)),
+    ("newton", Unit::Temperature(TemperatureScale::Newton)),
+    ("°Rø", Unit::Temperature(TemperatureScale::Romer)),
+    ("°Ro", Unit::Temperature(TemperatureScale::Romer)),
+    ("romer", Unit::Temperature(TemperatureScale::Romer)),
+    // pressure
+    ("Pa", Unit::Pressure(PressureUnit::Pascal)),
+    ("pascal", Unit::Pressure(PressureUnit::Pascal)),
+    ("hPa", Unit::Pressure(PressureUnit::Hectopascal)),
+    ("hectopascal", Unit::Pressure(PressureUnit::Hectopascal)),
+    ("kPa", Unit::Pressure(PressureUnit::Kilopascal)),
+    ("kilopascal", Unit::Pressure(PressureUnit::Kilopascal)),
+    ("bar", Unit::Pressure(PressureUnit::Bar)),
+    ("mbar", Unit::Pressure(PressureUnit::Millibar)),
+    ("mb", Unit::Pressure(PressureUnit::Millibar)),
+    ("millibar", Unit::Pressure(PressureUnit::Millibar)),
+    ("mmHg", Unit::Pressure(PressureUnit::MillimeterOfMercury)),
+    ("mm Hg", Unit::Pressure(PressureUnit::MillimeterOfMercury)),
+    ("mm_Hg", Unit::Pressure(PressureUnit::MillimeterOfMercury)),
+    ("inHg", Unit::Pressure(PressureUnit::InchOfMercury)),
+    ("in Hg", Unit::Pressure(PressureUnit::InchOfMercury)),
+    ("in_Hg", Unit::Pressure(PressureUnit::InchOfMercury)),
+    ("psi", Unit::Pressure(PressureUnit::Psi)),
+    ("atm", Unit::Pressure(PressureUnit::Atmosphere)),
+    ("atmosphere", Unit::Pressure(PressureUnit::Atmosphere)),
+    ("Torr", Unit::Pressure(PressureUnit::Torr)),
+    // speed
+    ("m/s", Unit::Speed(SpeedUnit::MetersPerSecond)),
+    ("m s-1", Unit::Speed(SpeedUnit::MetersPerSecond)),
+    ("m s**-1", Unit::Speed(SpeedUnit::MetersPerSecond)),
+    ("m.s-1", Unit::Speed(SpeedUnit::MetersPerSecond)),
+    ("mps", Unit::Speed(SpeedUnit::MetersPerSecond)),
+    ("km/h", Unit::Speed(SpeedUnit::KilometersPerHour)),
+    ("km h-1", Unit::Speed(SpeedUnit::KilometersPerHour)),
+    ("kmh", Unit::Speed(SpeedUnit::KilometersPerHour)),
+    ("kph", Unit::Speed(SpeedUnit::KilometersPerHour)),
+    ("kmph", Unit::Speed(SpeedUnit::KilometersPerHour)),
+    ("mph", Unit::Speed(SpeedUnit::MilesPerHour)),
+    ("mi/h", Unit::Speed(SpeedUnit::MilesPerHour)),
+    ("kt", Unit::Speed(SpeedUnit::Knots)),
+    ("kts", Unit::Speed(SpeedUnit::Knots)),
+    ("kn", Unit::Speed(SpeedUnit::Knots)),
+    ("knot", Unit::Speed(SpeedUnit::Knots)),
+    ("knots", Unit::Speed(SpeedUnit::Knots)),
+    ("ft/s", Unit::Speed(SpeedUnit::FeetPerSecond)),
+    ("ft s-1", Unit::Speed(SpeedUnit::FeetPerSecond)),
+    ("fps", Unit::Speed(SpeedUnit::FeetPerSecond)),
+    ("km/s", Unit::Speed(SpeedUnit::KilometersPerSecond)),
+    ("km s-1", Unit::Speed(SpeedUnit::KilometersPerSecond)),
+    (
+        "Mach",
+        Unit::Speed(SpeedUnit::Mach(STANDARD_ATMOSPHERE_TEMPERATURE)),
+    ),
+    ("Bft", Unit::Speed(SpeedUnit::Beaufort)),
+    ("beaufort", Unit::Speed(SpeedUnit::Beaufort)),
+    // precipitation rate
+    (
+        "mm/h",
+        Unit::PrecipitationRate(PrecipitationRateUnit::MillimetersPerHour),
+    ),
+    (
+        "mm h-1",
+        Unit::PrecipitationRate(PrecipitationRateUnit::MillimetersPerHour),
+    ),
+    (
+        "in/h",
+        Unit::PrecipitationRate(PrecipitationRateUnit::InchesPerHour),
+    ),
+    (
+        "in h-1",
+        Unit::PrecipitationRate(PrecipitationRateUnit::InchesPerHour),
+    ),
+    (
+        "kg m-2 s-1",
+        Unit::PrecipitationRate(PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond),
+    ),
+    (
+        "kg m**-2 s**-1",
+        Unit::PrecipitationRate(PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond),
+    ),
+    (
+        "kg/m2/s",
+        Unit::PrecipitationRate(PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond),
+    ),
+    // length
+    ("mm", Unit::Length(LengthUnit::Millimeter)),
+    ("millimeter", Unit::Length(LengthUnit::Millimeter)),
+    ("millimetre", Unit::Length(LengthUnit::Millimeter)),
+    ("cm", Unit::Length(LengthUnit::Centimeter)),
+    ("centimeter", Unit::Length(LengthUnit::Centimeter)),
+    ("centimetre", Unit::Length(LengthUnit::Centimeter)),
+    ("m", Unit::Length(LengthUnit::Meter)),
+    ("meter", Unit::Length(LengthUnit::Meter)),
+    ("metre", Unit::Length(LengthUnit::Meter)),
+    ("km", Unit::Length(LengthUnit::Kilometer)),
+    ("kilometer", Unit::Length(LengthUnit::Kilometer)),
+    ("kilometre", Unit::Length(LengthUnit::Kilometer)),
+    ("in", Unit::Length(LengthUnit::Inch)),
+    ("inch", Unit::Length(LengthUnit::Inch)),
+    ("inches", Unit::Length(LengthUnit::Inch)),
+    ("ft", Unit::Length(LengthUnit::Foot)),
+    ("foot", Unit::Length(LengthUnit::Foot)),
+    ("feet", Unit::Length(LengthUnit::Foot)),
+    ("mi", Unit::Length(LengthUnit::StatuteMile)),
+    ("SM", Unit::Length(LengthUnit::StatuteMile)),
+    ("mile", Unit::Length(LengthUnit::StatuteMile)),
+    ("miles", Unit::Length(LengthUnit::StatuteMile)),
+    ("NM", Unit::Length(LengthUnit::NauticalMile)),
+    ("nmi", Unit::Length(LengthUnit::NauticalMile)),
+    ("nautical_mile", Unit::Length(LengthUnit::NauticalMile)),
+];
+
+// Aliases of `UNIT_SYMBOLS` matched case-insensitively. Single letters and SI prefixed symbols
+// are left out, as other case is another unit, e.g. `nm` nanometre or `Mm` megametre.
+const CASE_INSENSITIVE_SYMBOLS: &[&str] = &[
+    "°C",
+    "degC",
+    "deg_C",
+    "degree_C",
+    "degrees_C",
+    "degree_Celsius",
+    "degrees_Celsius",
+    "celsius",
+    "°F",
+    "degF",
+    "deg_F",
+    "degree_F",
+    "degrees_F",
+    "degree_Fahrenheit",
+    "fahrenheit",
+    "kelvin",
+    "degK",
+    "degR",
+    "rankine",
+    "reaumur",
+    "delisle",
+    "newton",
+    "romer",
+    "pascal",
+    "hPa",
+    "hectopascal",
+    "kPa",
+    "kilopascal",
+    "bar",
+    "mbar",
+    "millibar",
+    "mmHg",
+    "inHg",
+    "psi",
+    "atm",
+    "atmosphere",
+    "Torr",
+    "m/s",
+    "mps",
+    "km/h",
+    "kmh",
+    "kph",
+    "kmph",
+    "mph",
+    "mi/h",
+    "kt",
+    "kts",
+    "knot",
+    "knots",
+    "ft/s",
+    "fps",
+    "km/s",
+    "Mach",
+    "Bft",
+    "beaufort",
+    "mm/h",
+    "in/h",
+    "millimeter",
+    "millimetre",
+    "centimeter",
+    "centimetre",
+    "meter",
+    "metre",
+    "km",
+    "kilometer",
+    "kilometre",
+    "inch",
+    "inches",
+    "ft",
+    "foot",
+    "feet",
+    "mile",
+    "miles",
+    "nmi",
+    "nautical_mile",
+];
+
+impl Unit {
+    /// Returns physical dimension of the unit.
+    pub fn dimension(&self) -> Dimension {
+        match self {
+            Unit::Temperature(_) => Dimension::Temperature,
+            Unit::Pressure(_) => Dimension::Pressure,
+            Unit::Speed(_) => Dimension::Speed,
+            Unit::Length(_) => Dimension::Length,
+            Unit::PrecipitationRate(_) => Dimension::PrecipitationRate,
+        }
+    }
+
+    /// Returns the usual symbol of the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{SpeedUnit, Unit};
+    ///
+    /// assert_eq!(Unit::Speed(SpeedUnit::Knots).symbol(), "kt");
+    /// ```
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::Temperature(scale) => match scale {
+                TemperatureScale::Celsius => "°C",
+                TemperatureScale::Fahrenheit => "°F",
+                TemperatureScale::Kelvin => "K",
+                TemperatureScale::Rankine => "°R",
+                TemperatureScale::Reaumur => "°Ré",
+                TemperatureScale::Delisle => "°De",
+                TemperatureScale::Newton => "°N",
+                TemperatureScale::Romer => "°Rø",
+            },
+            Unit::Pressure(unit) => match unit {
+                PressureUnit::Pascal => "Pa",
+                PressureUnit::Hectopascal => "hPa",
+                PressureUnit::Kilopascal => "kPa",
+                PressureUnit::Bar => "bar",
+                PressureUnit::Millibar => "mbar",
+                PressureUnit::MillimeterOfMercury => "mmHg",
+                PressureUnit::InchOfMercury => "inHg",
+                PressureUnit::Psi => "psi",
+                PressureUnit::Atmosphere => "atm",
+                PressureUnit::Torr => "Torr",
+            },
+            Unit::Speed(unit) => match unit {
+                SpeedUnit::MetersPerSecond => "m/s",
+                SpeedUnit::KilometersPerHour => "km/h",
+                SpeedUnit::MilesPerHour => "mph",
+                SpeedUnit::Knots => "kt",
+                SpeedUnit::FeetPerSecond => "ft/s",
+                SpeedUnit::KilometersPerSecond => "km/s",
+                SpeedUnit::Mach(_) => "Mach",
+                SpeedUnit::Beaufort => "Bft",
+            },
+            Unit::Length(unit) => match unit {
+                LengthUnit::Millimeter => "mm",
+                LengthUnit::Centimeter => "cm",
+                LengthUnit::Meter => "m",
+                LengthUnit::Kilometer => "km",
+                LengthUnit::Inch => "in",
+                LengthUnit::Foot => "ft",
+                LengthUnit::StatuteMile => "mi",
+                LengthUnit::NauticalMile => "NM",
+            },
+            Unit::PrecipitationRate(unit) => match unit {
+                PrecipitationRateUnit::MillimetersPerHour => "mm/h",
+                PrecipitationRateUnit::InchesPerHour => "in/h",
+                PrecipitationRateUnit::KilogramsPerSquareMeterPerSecond => "kg m-2 s-1",
+            },
+        }
+    }
+}
+
+// Mach number at other temperature than 15 °C prints as `Mach(t)` with the temperature in Celsius,
+// so it parses back to the same unit
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Speed(SpeedUnit::Mach(temperature))
+                if *temperature != STANDARD_ATMOSPHERE_TEMPERATURE =>
+            {
+                write!(f, "Mach({temperature})")
+            }
+            _ => f.write_str(self.symbol()),
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = UnitError;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        let symbol = symbol.trim();
+        UNIT_SYMBOLS
+            .iter()
+            .find(|(alias, _)| *alias == symbol)
+            .or_else(|| {
+                UNIT_SYMBOLS
+                    .iter()
+                    .filter(|(alias, _)| CASE_INSENSITIVE_SYMBOLS.contains(alias))
+                    .find(|(alias, _)| alias.eq_ignore_ascii_case(symbol))
+            })
+            .map(|(_, unit)| *unit)
+            .or_else(|| {
+                symbol
+                    .strip_prefix("Mach(")?
+                    .strip_suffix(')')?
+                    .trim()
+                    .parse()
+                    .ok()
+                    .map(|temperature| Unit::Speed(SpeedUnit::Mach(temperature)))
+            })
+            .ok_or(UnitError::UnknownUnit)
+    }
+}
+
+// Units are serialized as they print, so Mach number keeps its temperature
+#[cfg(feature = "serde")]
+impl serde::Serialize for Unit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Unit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct UnitVisitor;
+
+        impl serde::de::Visitor<'_> for UnitVisitor {
+            type Value = Unit;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("unit symbol")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, symbol: &str) -> Result<Unit, E> {
+                symbol
+                    .parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(symbol), &self))
+            }
+        }
+
+        deserializer.deserialize_str(UnitVisitor)
+    }
+}
+
+/// Error returned by runtime unit conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitError {
+    /// Unit symbol is not recognised.
+    UnknownUnit,
+    /// Units measure different physical quantities.
+    DimensionMismatch {
+        /// Dimension of the unit converted from.
+        from: Dimension,
+        /// Dimension of the unit converted to.
+        to: Dimension,
+    },
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitError::UnknownUnit => f.write_str("unknown unit"),
+            UnitError::DimensionMismatch { from, to } => {
+                write!(f, "cannot convert {} to {}", from, to)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitError {}
+
+/// Converts value between any two units of the same dimension.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{PressureUnit, SpeedUnit, Unit, UnitError, Dimension};
+///
+/// let pressure = 1013.25;
+/// let result = Ok(29.9213);
+///
+/// let inhg = meteo_tools::convert_unit(&pressure, Unit::Pressure(PressureUnit::Hectopascal), Unit::Pressure(PressureUnit::InchOfMercury));
+/// let mismatch = meteo_tools::convert_unit(&pressure, Unit::Pressure(PressureUnit::Hectopascal), Unit::Speed(SpeedUnit::Knots));
+///
+/// assert_eq!(inhg, result);
+/// assert_eq!(mismatch, Err(UnitError::DimensionMismatch { from: Dimension::Pressure, to: Dimension::Speed }));
+/// ```
+pub fn convert_unit<F: Float>(value: &F, from: Unit, to: Unit) -> Result<F, UnitError> {
+    match (from, to) {
+        (Unit::Temperature(from), Unit::Temperature(to)) => {
+            Ok(convert_temperature(value, from, to))
+        }
+        (Unit::Pressure(from), Unit::Pressure(to)) => Ok(convert_pressure(value, from, to)),
+        (Unit::Speed(from), Unit::Speed(to)) => Ok(convert_speed(value, from, to)),
+        (Unit::Length(from), Unit::Length(to)) => Ok(convert_length(value, from, to)),
+        (Unit::PrecipitationRate(from), Unit::PrecipitationRate(to)) => {
+            Ok(convert_precipitation_rate(value, from, to))
+        }
+        (from, to) => Err(UnitError::DimensionMismatch {
+            from: from.dimension(),
+            to: to.dimension(),
+        }),
+    }
+}
+
+/// Converts value between any two units of the same dimension given by their symbols.
+///
+/// # Examples
+///
+/// ```
+/// let temperature = 72.5;
+/// let result = Ok(22.5);
+///
+/// let celsius = meteo_tools::convert_units(&temperature, "°F", "degC");
+///
+/// assert_eq!(celsius, result);
+/// ```
+pub fn convert_units<F: Float>(value: &F, from: &str, to: &str) -> Result<F, UnitError> {
+    convert_unit(value, from.parse()?, to.parse()?)
+}
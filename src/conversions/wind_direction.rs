@@ -0,0 +1,245 @@
+use core::fmt;
+
+use crate::Float;
+
+/// Resolution of the compass rose used when converting degrees to compass points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompassPoints {
+    /// Cardinal directions only (N, E, S, W).
+    Four,
+    /// Cardinal and intercardinal directions (N, NE, E, ...).
+    Eight,
+    /// Adds the secondary intercardinal directions (N, NNE, NE, ...).
+    Sixteen,
+    /// Full mariner's compass including the "by" points (N, NbE, NNE, ...).
+    ThirtyTwo,
+}
+
+impl CompassPoints {
+    /// Returns the number of points on the compass rose.
+    pub fn count(&self) -> usize {
+        match self {
+            CompassPoints::Four => 4,
+            CompassPoints::Eight => 8,
+            CompassPoints::Sixteen => 16,
+            CompassPoints::ThirtyTwo => 32,
+        }
+    }
+}
+
+/// Language of the compass point abbreviations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompassLanguage {
+    /// N, E, S, W with "b" for "by" (NbE).
+    English,
+    /// N, O, S, W with "z" for "zu" (NzO).
+    German,
+    /// N, E, S, O with "q" for "quart" (NqE).
+    French,
+    /// N, E, S, O with "c" for "cuarta" (NcE).
+    Spanish,
+}
+
+impl CompassLanguage {
+    fn points(&self) -> &'static [&'static str; 32] {
+        match self {
+            CompassLanguage::English => &ENGLISH_POINTS,
+            CompassLanguage::German => &GERMAN_POINTS,
+            CompassLanguage::French => &FRENCH_POINTS,
+            CompassLanguage::Spanish => &SPANISH_POINTS,
+        }
+    }
+}
+
+const ENGLISH_POINTS: [&str; 32] = [
+    "N", "NbE", "NNE", "NEbN", "NE", "NEbE", "ENE", "EbN", "E", "EbS", "ESE", "SEbE", "SE", "SEbS",
+    "SSE", "SbE", "S", "SbW", "SSW", "SWbS", "SW", "SWbW", "WSW", "WbS", "W", "WbN", "WNW", "NWbW",
+    "NW", "NWbN", "NNW", "NbW",
+];
+const GERMAN_POINTS: [&str; 32] = [
+    "N", "NzO", "NNO", "NOzN", "NO", "NOzO", "ONO", "OzN", "O", "OzS", "OSO", "SOzO", "SO", "SOzS",
+    "SSO", "SzO", "S", "SzW", "SSW", "SWzS", "SW", "SWzW", "WSW", "WzS", "W", "WzN", "WNW", "NWzW",
+    "NW", "NWzN", "NNW", "NzW",
+];
+const FRENCH_POINTS: [&str; 32] = [
+    "N", "NqE", "NNE", "NEqN", "NE", "NEqE", "ENE", "EqN", "E", "EqS", "ESE", "SEqE", "SE", "SEqS",
+    "SSE", "SqE", "S", "SqO", "SSO", "SOqS", "SO", "SOqO", "OSO", "OqS", "O", "OqN", "ONO", "NOqO",
+    "NO", "NOqN", "NNO", "NqO",
+];
+const SPANISH_POINTS: [&str; 32] = [
+    "N", "NcE", "NNE", "NEcN", "NE", "NEcE", "ENE", "EcN", "E", "EcS", "ESE", "SEcE", "SE", "SEcS",
+    "SSE", "ScE", "S", "ScO", "SSO", "SOcS", "SO", "SOcO", "OSO", "OcS", "O", "OcN", "ONO", "NOcO",
+    "NO", "NOcN", "NNO", "NcO",
+];
+
+// Angle between two neighbouring points of the 32-point compass
+const THIRTY_TWO_POINT_STEP: f64 = 360.0 / 32.0;
+
+/// Wind direction as reported by a station.
+///
+/// Variable and calm winds have no meaningful direction, so they are kept as separate cases
+/// instead of being encoded as magic numbers such as 0 or 990.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindDirection {
+    /// Direction the wind blows from in degrees, clockwise from true north.
+    Degrees(f64),
+    /// Direction is variable (VRB).
+    Variable,
+    /// No measurable wind.
+    Calm,
+}
+
+impl WindDirection {
+    /// Returns direction in degrees, or `None` for variable and calm winds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::WindDirection;
+    ///
+    /// assert_eq!(WindDirection::Degrees(270.0).degrees(), Some(270.0));
+    /// assert_eq!(WindDirection::Calm.degrees(), None);
+    /// ```
+    pub fn degrees(&self) -> Option<f64> {
+        match self {
+            WindDirection::Degrees(degrees) => Some(*degrees),
+            WindDirection::Variable | WindDirection::Calm => None,
+        }
+    }
+
+    /// Returns compass abbreviation of the direction, `VRB` for variable and `CALM` for calm wind.
+    ///
+    /// Returns `None` when the degrees are NaN or infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{CompassLanguage, CompassPoints, WindDirection};
+    ///
+    /// let direction = WindDirection::Degrees(22.5);
+    ///
+    /// assert_eq!(direction.compass(CompassPoints::Sixteen, CompassLanguage::English), Some("NNE"));
+    /// assert_eq!(WindDirection::Variable.compass(CompassPoints::Sixteen, CompassLanguage::English), Some("VRB"));
+    /// ```
+    pub fn compass(
+        &self,
+        points: CompassPoints,
+        language: CompassLanguage,
+    ) -> Option<&'static str> {
+        match self {
+            WindDirection::Degrees(degrees) => degrees_to_compass(degrees, points, language),
+            WindDirection::Variable => Some("VRB"),
+            WindDirection::Calm => Some("CALM"),
+        }
+    }
+}
+
+/// Error returned when a compass point or wind direction cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCompassError;
+
+impl fmt::Display for ParseCompassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid compass point or wind direction")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCompassError {}
+
+/// Converts wind direction in degrees to the nearest compass point abbreviation.
+///
+/// Degrees outside of 0-360 are wrapped around the compass. Returns `None` for NaN and infinite degrees
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{CompassLanguage, CompassPoints};
+///
+/// let direction = 200.0;
+///
+/// let compass = meteo_tools::degrees_to_compass(&direction, CompassPoints::Sixteen, CompassLanguage::English);
+/// let invalid = meteo_tools::degrees_to_compass(&f64::NAN, CompassPoints::Sixteen, CompassLanguage::English);
+///
+/// assert_eq!(compass, Some("SSW"));
+/// assert_eq!(invalid, None);
+/// ```
+pub fn degrees_to_compass<F: Float>(
+    degrees: &F,
+    points: CompassPoints,
+    language: CompassLanguage,
+) -> Option<&'static str> {
+    let degrees = degrees.to_f64();
+    if !degrees.is_finite() {
+        return None;
+    }
+    let count = points.count();
+    let step = 360.0 / count as f64;
+    let index = (degrees.rem_euclid(360.0) / step).round() as usize % count;
+    Some(language.points()[index * (32 / count)])
+}
+
+/// Converts compass point abbreviation to degrees. Comparison is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::CompassLanguage;
+///
+/// let degrees = meteo_tools::compass_to_degrees("NNO", CompassLanguage::German);
+///
+/// assert_eq!(degrees, Ok(22.5));
+/// ```
+pub fn compass_to_degrees<F: Float>(
+    compass: &str,
+    language: CompassLanguage,
+) -> Result<F, ParseCompassError> {
+    let compass = compass.trim();
+    language
+        .points()
+        .iter()
+        .position(|point| point.eq_ignore_ascii_case(compass))
+        .map(|index| F::from_f64(index as f64 * THIRTY_TWO_POINT_STEP))
+        .ok_or(ParseCompassError)
+}
+
+/// Parses wind direction given as degrees, compass point, `VRB` or `CALM`.
+///
+/// Besides the abbreviations, `VARIABLE` and `C` (calm) are accepted. Comparison is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{CompassLanguage, WindDirection};
+///
+/// let direction = meteo_tools::parse_wind_direction("WSW", CompassLanguage::English);
+/// let variable = meteo_tools::parse_wind_direction("VRB", CompassLanguage::English);
+/// let measured = meteo_tools::parse_wind_direction("245", CompassLanguage::English);
+///
+/// assert_eq!(direction, Ok(WindDirection::Degrees(247.5)));
+/// assert_eq!(variable, Ok(WindDirection::Variable));
+/// assert_eq!(measured, Ok(WindDirection::Degrees(245.0)));
+/// ```
+pub fn parse_wind_direction(
+    direction: &str,
+    language: CompassLanguage,
+) -> Result<WindDirection, ParseCompassError> {
+    let direction = direction.trim();
+    if direction.eq_ignore_ascii_case("VRB") || direction.eq_ignore_ascii_case("VARIABLE") {
+        return Ok(WindDirection::Variable);
+    }
+    if direction.eq_ignore_ascii_case("CALM") || direction.eq_ignore_ascii_case("C") {
+        return Ok(WindDirection::Calm);
+    }
+    if let Ok(degrees) = direction.parse::<f64>() {
+        return if (0.0..=360.0).contains(&degrees) {
+            Ok(WindDirection::Degrees(degrees))
+        } else {
+            Err(ParseCompassError)
+        };
+    }
+    compass_to_degrees(direction, language).map(WindDirection::Degrees)
+}
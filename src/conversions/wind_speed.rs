@@ -1,8 +1,8 @@
 use crate::constants::constants::{
-    IMPERIAL_SPEED_CONVERSION, KNOTS_IMPERIAL_SPEED_CONVERSION, KNOTS_METRIC_SPEED_CONVERSION,
-    METRIC_SPEED_CONVERSION,
+    BEAUFORT_COEFFICIENT, FEET_TO_METERS, KELVIN_DEGREE_CONSTANT, KNOTS_METRIC_SPEED_CONVERSION,
+    METRIC_SPEED_CONVERSION, MPH_TO_MPS, SPEED_OF_SOUND_COEFFICIENT,
 };
-use crate::meteo_round;
+use crate::{meteo_round, Float};
 
 /// Converts speed from Meters per second to Kilometers per hour.
 ///
@@ -16,8 +16,8 @@ use crate::meteo_round;
 ///
 /// assert_eq!(kmph, result);
 /// ```
-pub fn mps_to_kmph(speed_mps: &f64) -> f64 {
-    let kmph = speed_mps * METRIC_SPEED_CONVERSION;
+pub fn mps_to_kmph<F: Float>(speed_mps: &F) -> F {
+    let kmph = *speed_mps * F::from_f64(METRIC_SPEED_CONVERSION);
     meteo_round(&kmph)
 }
 
@@ -33,8 +33,8 @@ pub fn mps_to_kmph(speed_mps: &f64) -> f64 {
 ///
 /// assert_eq!(mps, result);
 /// ```
-pub fn kmph_to_mps(speed_kmph: &f64) -> f64 {
-    let mps = speed_kmph / METRIC_SPEED_CONVERSION;
+pub fn kmph_to_mps<F: Float>(speed_kmph: &F) -> F {
+    let mps = *speed_kmph / F::from_f64(METRIC_SPEED_CONVERSION);
     meteo_round(&mps)
 }
 
@@ -50,9 +50,12 @@ pub fn kmph_to_mps(speed_kmph: &f64) -> f64 {
 ///
 /// assert_eq!(mph, result);
 /// ```
-pub fn kmph_to_mph(speed_kmph: &f64) -> f64 {
-    let mph = speed_kmph * IMPERIAL_SPEED_CONVERSION;
-    meteo_round(&mph)
+pub fn kmph_to_mph<F: Float>(speed_kmph: &F) -> F {
+    convert_speed(
+        speed_kmph,
+        SpeedUnit::KilometersPerHour,
+        SpeedUnit::MilesPerHour,
+    )
 }
 
 /// Converts speed from Miles per hour to Kilometers per hour.
@@ -67,9 +70,12 @@ pub fn kmph_to_mph(speed_kmph: &f64) -> f64 {
 ///
 /// assert_eq!(kmph, result);
 /// ```
-pub fn mph_to_kmph(speed_mph: &f64) -> f64 {
-    let kmph = speed_mph / IMPERIAL_SPEED_CONVERSION;
-    meteo_round(&kmph)
+pub fn mph_to_kmph<F: Float>(speed_mph: &F) -> F {
+    convert_speed(
+        speed_mph,
+        SpeedUnit::MilesPerHour,
+        SpeedUnit::KilometersPerHour,
+    )
 }
 
 /// Converts speed from Meters per second to Miles per hour.
@@ -84,8 +90,8 @@ pub fn mph_to_kmph(speed_mph: &f64) -> f64 {
 ///
 /// assert_eq!(mps, result);
 /// ```
-pub fn mph_to_mps(speed_mph: &f64) -> f64 {
-    let mps = speed_mph * 0.44704;
+pub fn mph_to_mps<F: Float>(speed_mph: &F) -> F {
+    let mps = *speed_mph * F::from_f64(MPH_TO_MPS);
     meteo_round(&mps)
 }
 
@@ -101,10 +107,12 @@ pub fn mph_to_mps(speed_mph: &f64) -> f64 {
 ///
 /// assert_eq!(mph, result);
 /// ```
-pub fn mps_to_mph(speed_mps: &f64) -> f64 {
-    let kmph = mps_to_kmph(speed_mps);
-    let mph = kmph_to_mph(&kmph);
-    meteo_round(&mph)
+pub fn mps_to_mph<F: Float>(speed_mps: &F) -> F {
+    convert_speed(
+        speed_mps,
+        SpeedUnit::MetersPerSecond,
+        SpeedUnit::MilesPerHour,
+    )
 }
 
 /// Converts speed from Meters per second to Knots per hour.
@@ -119,9 +127,8 @@ pub fn mps_to_mph(speed_mps: &f64) -> f64 {
 ///
 /// assert_eq!(kts, result);
 /// ```
-pub fn mps_to_knots(speed_mps: &f64) -> f64 {
-    let kmph = mps_to_kmph(speed_mps);
-    kmph_to_knots(&kmph)
+pub fn mps_to_knots<F: Float>(speed_mps: &F) -> F {
+    convert_speed(speed_mps, SpeedUnit::MetersPerSecond, SpeedUnit::Knots)
 }
 
 /// Converts speed from Knots per hour to Meters per second.
@@ -136,9 +143,8 @@ pub fn mps_to_knots(speed_mps: &f64) -> f64 {
 ///
 /// assert_eq!(kts, result);
 /// ```
-pub fn knots_to_mps(speed_knots: &f64) -> f64 {
-    let kmph = knots_to_kmph(speed_knots);
-    kmph_to_mps(&kmph)
+pub fn knots_to_mps<F: Float>(speed_knots: &F) -> F {
+    convert_speed(speed_knots, SpeedUnit::Knots, SpeedUnit::MetersPerSecond)
 }
 
 /// Converts speed from Kilometers per hour to Knots per hour.
@@ -153,9 +159,8 @@ pub fn knots_to_mps(speed_knots: &f64) -> f64 {
 ///
 /// assert_eq!(kts, result);
 /// ```
-pub fn kmph_to_knots(speed_kmph: &f64) -> f64 {
-    let kts = speed_kmph / KNOTS_METRIC_SPEED_CONVERSION;
-    meteo_round(&kts)
+pub fn kmph_to_knots<F: Float>(speed_kmph: &F) -> F {
+    convert_speed(speed_kmph, SpeedUnit::KilometersPerHour, SpeedUnit::Knots)
 }
 
 /// Converts speed from Knots per hour to Kilometers per hour.
@@ -170,9 +175,8 @@ pub fn kmph_to_knots(speed_kmph: &f64) -> f64 {
 ///
 /// assert_eq!(kmph, result);
 /// ```
-pub fn knots_to_kmph(speed_knots: &f64) -> f64 {
-    let kmh = speed_knots * KNOTS_METRIC_SPEED_CONVERSION;
-    meteo_round(&kmh)
+pub fn knots_to_kmph<F: Float>(speed_knots: &F) -> F {
+    convert_speed(speed_knots, SpeedUnit::Knots, SpeedUnit::KilometersPerHour)
 }
 
 /// Converts speed from Knots per hour to Miles per hour.
@@ -188,9 +192,8 @@ pub fn knots_to_kmph(speed_knots: &f64) -> f64 {
 ///
 /// assert_eq!(mph, result);
 /// ```
-pub fn knots_to_mph(speed_knots: &f64) -> f64 {
-    let mph = speed_knots * KNOTS_IMPERIAL_SPEED_CONVERSION;
-    meteo_round(&mph)
+pub fn knots_to_mph<F: Float>(speed_knots: &F) -> F {
+    convert_speed(speed_knots, SpeedUnit::Knots, SpeedUnit::MilesPerHour)
 }
 
 /// Converts speed from Miles per hour to Knots per hour.
@@ -206,7 +209,336 @@ pub fn knots_to_mph(speed_knots: &f64) -> f64 {
 ///
 /// assert_eq!(kts, result);
 /// ```
-pub fn mph_to_knots(speed_mph: &f64) -> f64 {
-    let kts = speed_mph / KNOTS_IMPERIAL_SPEED_CONVERSION;
-    meteo_round(&kts)
+pub fn mph_to_knots<F: Float>(speed_mph: &F) -> F {
+    convert_speed(speed_mph, SpeedUnit::MilesPerHour, SpeedUnit::Knots)
+}
+
+/// Unit of speed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpeedUnit {
+    /// Meters per second (m/s).
+    MetersPerSecond,
+    /// Kilometers per hour (km/h).
+    KilometersPerHour,
+    /// Miles per hour (mph).
+    MilesPerHour,
+    /// Knots (kt).
+    Knots,
+    /// Feet per second (ft/s).
+    FeetPerSecond,
+    /// Kilometers per second (km/s).
+    KilometersPerSecond,
+    /// Mach number at given air temperature in Celsius, which determines the speed of sound.
+    Mach(f64),
+    /// Beaufort scale force. Not a linear unit, speeds convert to whole forces 0-12
+    /// and forces convert to the speed given by the empirical Beaufort equation.
+    Beaufort,
+}
+
+impl SpeedUnit {
+    /// Returns how many Meters per second one unit of this speed unit is, `None` for Beaufort.
+    pub fn meters_per_second(&self) -> Option<f64> {
+        match self {
+            SpeedUnit::Mach(temperature) => Some(speed_of_sound(temperature)),
+            SpeedUnit::Beaufort => None,
+            unit => Some(linear_meters_per_second(*unit)),
+        }
+    }
+
+    /// Converts speed in Meters per second to this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::SpeedUnit;
+    ///
+    /// let speed = 10.0;
+    /// let result = 36.0;
+    ///
+    /// let kmph = SpeedUnit::KilometersPerHour.convert_mps(&speed);
+    ///
+    /// assert_eq!(kmph, result);
+    /// ```
+    pub fn convert_mps<F: Float>(&self, speed_mps: &F) -> F {
+        convert_speed(speed_mps, SpeedUnit::MetersPerSecond, *self)
+    }
+}
+
+/// Converts speed between any two speed units.
+///
+/// Linear units are converted through their factor to Meters per second without intermediate rounding.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::SpeedUnit;
+///
+/// let speed = 250.0;
+/// let result = 0.4032;
+///
+/// let mach = meteo_tools::convert_speed(&speed, SpeedUnit::Knots, SpeedUnit::Mach(-20.0));
+///
+/// assert_eq!(mach, result);
+/// ```
+///
+/// Beaufort forces are whole numbers from 0 to 12, negative speeds give force 0 and NaN gives NaN.
+///
+/// ```
+/// use meteo_tools::SpeedUnit;
+///
+/// let speed = 50.0;
+/// let result = 7.0;
+///
+/// let beaufort = meteo_tools::convert_speed(&speed, SpeedUnit::KilometersPerHour, SpeedUnit::Beaufort);
+/// let negative = meteo_tools::convert_speed(&-1.0, SpeedUnit::MetersPerSecond, SpeedUnit::Beaufort);
+/// let invalid = meteo_tools::convert_speed(&f64::NAN, SpeedUnit::MetersPerSecond, SpeedUnit::Beaufort);
+///
+/// assert_eq!(beaufort, result);
+/// assert_eq!(negative, 0.0);
+/// assert!(invalid.is_nan());
+/// ```
+pub fn convert_speed<F: Float>(speed: &F, from: SpeedUnit, to: SpeedUnit) -> F {
+    let speed_mps = match from.meters_per_second() {
+        Some(factor) => speed.to_f64() * factor,
+        None => BEAUFORT_COEFFICIENT * speed.to_f64().powf(1.5),
+    };
+    match to.meters_per_second() {
+        Some(factor) => meteo_round(&F::from_f64(speed_mps / factor)),
+        // `max` would turn NaN into force 0
+        None if speed_mps.is_nan() => F::from_f64(f64::NAN),
+        None => F::from_f64(
+            (speed_mps.max(0.0) / BEAUFORT_COEFFICIENT)
+                .powf(2.0 / 3.0)
+                .round()
+                .min(12.0),
+        ),
+    }
+}
+
+/// Converts speed between any two linear speed units, `const` variant of [`convert_speed`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+/// Mach and Beaufort are not supported, as the speed of sound needs a square root and Beaufort is not linear,
+/// using them panics, which fails the compilation when evaluated at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::SpeedUnit;
+///
+/// const LIMIT: f64 = meteo_tools::const_convert_speed(0.5, SpeedUnit::KilometersPerSecond, SpeedUnit::MetersPerSecond);
+///
+/// assert_eq!(LIMIT, 500.0);
+/// ```
+pub const fn const_convert_speed(speed: f64, from: SpeedUnit, to: SpeedUnit) -> f64 {
+    speed * (linear_meters_per_second(from) / linear_meters_per_second(to))
+}
+
+// Factor of a linear speed unit to Meters per second usable in `const fn`
+const fn linear_meters_per_second(unit: SpeedUnit) -> f64 {
+    match unit {
+        SpeedUnit::MetersPerSecond => 1.0,
+        SpeedUnit::KilometersPerHour => 1.0 / METRIC_SPEED_CONVERSION,
+        SpeedUnit::MilesPerHour => MPH_TO_MPS,
+        SpeedUnit::Knots => KNOTS_METRIC_SPEED_CONVERSION / METRIC_SPEED_CONVERSION,
+        SpeedUnit::FeetPerSecond => FEET_TO_METERS,
+        SpeedUnit::KilometersPerSecond => 1000.0,
+        SpeedUnit::Mach(_) | SpeedUnit::Beaufort => {
+            panic!("Mach and Beaufort are not linear speed units")
+        }
+    }
+}
+
+// Speed of sound in dry air in m/s for given temperature in Celsius
+fn speed_of_sound(temperature: &f64) -> f64 {
+    SPEED_OF_SOUND_COEFFICIENT * (temperature + KELVIN_DEGREE_CONSTANT).sqrt()
+}
+
+/// Converts speed from Meters per second to Kilometers per hour, `const` variant of [`mps_to_kmph`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mps_to_kmph(10.0);
+///
+/// assert_eq!(LIMIT, 36.0);
+/// ```
+pub const fn const_mps_to_kmph(speed_mps: f64) -> f64 {
+    speed_mps * METRIC_SPEED_CONVERSION
+}
+
+/// Converts speed from Kilometers per hour to Meters per second, `const` variant of [`kmph_to_mps`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_kmph_to_mps(36.0);
+///
+/// assert_eq!(LIMIT, 10.0);
+/// ```
+pub const fn const_kmph_to_mps(speed_kmph: f64) -> f64 {
+    speed_kmph / METRIC_SPEED_CONVERSION
+}
+
+/// Converts speed from Kilometers per hour to Miles per hour, `const` variant of [`kmph_to_mph`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_kmph_to_mph(10.0);
+///
+/// assert!((LIMIT - 6.2137).abs() < 1e-4);
+/// ```
+pub const fn const_kmph_to_mph(speed_kmph: f64) -> f64 {
+    const_convert_speed(
+        speed_kmph,
+        SpeedUnit::KilometersPerHour,
+        SpeedUnit::MilesPerHour,
+    )
+}
+
+/// Converts speed from Miles per hour to Kilometers per hour, `const` variant of [`mph_to_kmph`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mph_to_kmph(62.1371);
+///
+/// assert!((LIMIT - 100.0).abs() < 1e-4);
+/// ```
+pub const fn const_mph_to_kmph(speed_mph: f64) -> f64 {
+    const_convert_speed(
+        speed_mph,
+        SpeedUnit::MilesPerHour,
+        SpeedUnit::KilometersPerHour,
+    )
+}
+
+/// Converts speed from Miles per hour to Meters per second, `const` variant of [`mph_to_mps`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mph_to_mps(10.0);
+///
+/// assert_eq!(LIMIT, 4.4704);
+/// ```
+pub const fn const_mph_to_mps(speed_mph: f64) -> f64 {
+    speed_mph * MPH_TO_MPS
+}
+
+/// Converts speed from Meters per second to Miles per hour, `const` variant of [`mps_to_mph`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mps_to_mph(4.4704);
+///
+/// assert_eq!(LIMIT, 10.0);
+/// ```
+pub const fn const_mps_to_mph(speed_mps: f64) -> f64 {
+    speed_mps / MPH_TO_MPS
+}
+
+/// Converts speed from Meters per second to Knots, `const` variant of [`mps_to_knots`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mps_to_knots(1.852);
+///
+/// assert_eq!(LIMIT, 3.6);
+/// ```
+pub const fn const_mps_to_knots(speed_mps: f64) -> f64 {
+    speed_mps * METRIC_SPEED_CONVERSION / KNOTS_METRIC_SPEED_CONVERSION
+}
+
+/// Converts speed from Knots to Meters per second, `const` variant of [`knots_to_mps`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_knots_to_mps(36.0);
+///
+/// assert_eq!(LIMIT, 18.52);
+/// ```
+pub const fn const_knots_to_mps(speed_knots: f64) -> f64 {
+    speed_knots * KNOTS_METRIC_SPEED_CONVERSION / METRIC_SPEED_CONVERSION
+}
+
+/// Converts speed from Kilometers per hour to Knots, `const` variant of [`kmph_to_knots`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_kmph_to_knots(18.52);
+///
+/// assert_eq!(LIMIT, 10.0);
+/// ```
+pub const fn const_kmph_to_knots(speed_kmph: f64) -> f64 {
+    speed_kmph / KNOTS_METRIC_SPEED_CONVERSION
+}
+
+/// Converts speed from Knots to Kilometers per hour, `const` variant of [`knots_to_kmph`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_knots_to_kmph(10.0);
+///
+/// assert_eq!(LIMIT, 18.52);
+/// ```
+pub const fn const_knots_to_kmph(speed_knots: f64) -> f64 {
+    speed_knots * KNOTS_METRIC_SPEED_CONVERSION
+}
+
+/// Converts speed from Knots to Miles per hour, `const` variant of [`knots_to_mph`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_knots_to_mph(10.0);
+///
+/// assert!((LIMIT - 11.5078).abs() < 1e-4);
+/// ```
+pub const fn const_knots_to_mph(speed_knots: f64) -> f64 {
+    const_convert_speed(speed_knots, SpeedUnit::Knots, SpeedUnit::MilesPerHour)
+}
+
+/// Converts speed from Miles per hour to Knots, `const` variant of [`mph_to_knots`].
+///
+/// Can be evaluated at compile time, takes value by value and does not round the result.
+///
+/// # Examples
+///
+/// ```
+/// const LIMIT: f64 = meteo_tools::const_mph_to_knots(11.5078);
+///
+/// assert!((LIMIT - 10.0).abs() < 1e-4);
+/// ```
+pub const fn const_mph_to_knots(speed_mph: f64) -> f64 {
+    const_convert_speed(speed_mph, SpeedUnit::MilesPerHour, SpeedUnit::Knots)
 }
@@ -0,0 +1,210 @@
+use core::fmt::{Debug, Display};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// Floating point type accepted by calculations and conversions, implemented for `f32` and `f64`.
+///
+/// Constants of the crate are kept in `f64` and converted with [`Float::from_f64`], which rounds
+/// them to the nearest value representable in the target precision.
+///
+/// # Examples
+///
+/// ```
+/// let temperature: f32 = 22.5;
+/// let relative_humidity: f32 = 62.4;
+/// let result: f32 = 14.9477;
+///
+/// let dew_point = meteo_tools::common_celsius_dew_point(&temperature, &relative_humidity);
+///
+/// assert!((dew_point - result).abs() < 0.001);
+/// ```
+pub trait Float:
+    Copy
+    + Debug
+    + Display
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+{
+    /// Converts from `f64`, rounding to the nearest representable value.
+    fn from_f64(value: f64) -> Self;
+    /// Converts to `f64`.
+    fn to_f64(self) -> f64;
+    fn powf(self, exponent: Self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn round(self) -> Self;
+    fn abs(self) -> Self;
+    fn rem_euclid(self, divisor: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float {
+    ($float:ty) => {
+        impl Float for $float {
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                <$float>::powf(self, exponent)
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                <$float>::powi(self, exponent)
+            }
+
+            fn ln(self) -> Self {
+                <$float>::ln(self)
+            }
+
+            fn exp(self) -> Self {
+                <$float>::exp(self)
+            }
+
+            fn asin(self) -> Self {
+                <$float>::asin(self)
+            }
+
+            fn sin(self) -> Self {
+                <$float>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$float>::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                <$float>::tan(self)
+            }
+
+            fn acos(self) -> Self {
+                <$float>::acos(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$float>::sqrt(self)
+            }
+
+            fn round(self) -> Self {
+                <$float>::round(self)
+            }
+
+            fn abs(self) -> Self {
+                <$float>::abs(self)
+            }
+
+            fn rem_euclid(self, divisor: Self) -> Self {
+                <$float>::rem_euclid(self, divisor)
+            }
+
+            fn min(self, other: Self) -> Self {
+                <$float>::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                <$float>::max(self, other)
+            }
+        }
+    };
+}
+
+// Without std the math functions come from libm, which provides them for both precisions
+#[cfg(not(feature = "std"))]
+macro_rules! impl_float {
+    ($float:ty) => {
+        impl Float for $float {
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                libm::Libm::<$float>::pow(self, exponent)
+            }
+
+            fn powi(self, exponent: i32) -> Self {
+                libm::Libm::<$float>::pow(self, exponent as $float)
+            }
+
+            fn ln(self) -> Self {
+                libm::Libm::<$float>::log(self)
+            }
+
+            fn exp(self) -> Self {
+                libm::Libm::<$float>::exp(self)
+            }
+
+            fn asin(self) -> Self {
+                libm::Libm::<$float>::asin(self)
+            }
+
+            fn sin(self) -> Self {
+                libm::Libm::<$float>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::Libm::<$float>::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                libm::Libm::<$float>::tan(self)
+            }
+
+            fn acos(self) -> Self {
+                libm::Libm::<$float>::acos(self)
+            }
+
+            fn sqrt(self) -> Self {
+                libm::Libm::<$float>::sqrt(self)
+            }
+
+            fn round(self) -> Self {
+                libm::Libm::<$float>::round(self)
+            }
+
+            fn abs(self) -> Self {
+                libm::Libm::<$float>::fabs(self)
+            }
+
+            fn rem_euclid(self, divisor: Self) -> Self {
+                let remainder = libm::Libm::<$float>::fmod(self, divisor);
+                if remainder < 0.0 {
+                    remainder + libm::Libm::<$float>::fabs(divisor)
+                } else {
+                    remainder
+                }
+            }
+
+            fn min(self, other: Self) -> Self {
+                libm::Libm::<$float>::fmin(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                libm::Libm::<$float>::fmax(self, other)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
@@ -4,15 +4,37 @@
 //!
 //! This crate can convert and count meteorological values from measurements. Like conversion from C to F and Kelvin and vice versa. Counting
 //! dew point, heat index, humidex, mixing ratio, absolute humidity, and others will be added soon. See the docs for all functionality.
+//!
+//! The crate is `no_std` compatible. Disable the default `std` feature and enable `libm` feature,
+//! which provides the math functions, to use it on embedded targets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either `std` or `libm` feature must be enabled");
 
+pub mod batch;
 mod calculations;
 mod constants;
 pub mod conversions;
+mod float;
+mod observation;
+#[cfg(feature = "std")]
+pub mod reports;
+mod resolver;
+#[cfg(feature = "serde")]
+pub mod serialization;
 
+pub use self::float::Float;
+pub use self::observation::DerivedQuantities;
+pub use self::observation::Observation;
+pub use self::resolver::ResolveError;
+pub use self::resolver::Resolver;
+pub use self::resolver::Variable;
 use crate::constants::constants::{
-    HUMIDEX_CONSTANT_OFFSET, LATENT_HEAT_OF_VAPORIZATION, SATURATION_VAPOR_PRESSURE_REFERENCE,
-    STANDARD_CONDENSATION_POINT,
+    LATENT_HEAT_OF_VAPORIZATION, SATURATION_VAPOR_PRESSURE_REFERENCE, STANDARD_CONDENSATION_POINT,
 };
+
 // Dew point
 pub use self::calculations::dew_point::celsius_dew_point;
 pub use self::calculations::dew_point::common_celsius_dew_point;
@@ -39,20 +61,108 @@ pub use self::calculations::mixing_ratio::fahrenheit_mixing_ratio;
 pub use self::calculations::absolute_humidity::celsius_absolute_humidity;
 pub use self::calculations::absolute_humidity::fahrenheit_absolute_humidity;
 
+// Chill
+pub use self::calculations::chill::chill_hours;
+pub use self::calculations::chill::chill_portions;
+pub use self::calculations::chill::hourly_temperatures;
+pub use self::calculations::chill::utah_chill_units;
+
+// Degree days
+pub use self::calculations::degree_days::accumulated_cooling_degree_days;
+pub use self::calculations::degree_days::accumulated_growing_degree_days;
+pub use self::calculations::degree_days::accumulated_heating_degree_days;
+pub use self::calculations::degree_days::cooling_degree_days;
+pub use self::calculations::degree_days::growing_degree_days;
+pub use self::calculations::degree_days::heating_degree_days;
+pub use self::calculations::degree_days::DegreeDayMethod;
+pub use self::calculations::degree_days::Threshold;
+
+// Evapotranspiration
+pub use self::calculations::evapotranspiration::fao_hourly_reference_evapotranspiration;
+pub use self::calculations::evapotranspiration::fao_net_radiation;
+pub use self::calculations::evapotranspiration::fao_reference_evapotranspiration;
+pub use self::calculations::evapotranspiration::fao_saturation_vapor_pressure;
+pub use self::calculations::evapotranspiration::hargreaves_evapotranspiration;
+pub use self::calculations::evapotranspiration::pressure_at_elevation;
+pub use self::calculations::evapotranspiration::priestley_taylor_evapotranspiration;
+pub use self::calculations::evapotranspiration::psychrometric_constant;
+pub use self::calculations::evapotranspiration::saturation_vapor_pressure_slope;
+pub use self::calculations::evapotranspiration::DailyWeather;
+
+// Solar geometry
+pub use self::calculations::solar::day_length;
+pub use self::calculations::solar::equation_of_time;
+pub use self::calculations::solar::solar_declination;
+pub use self::calculations::solar::solar_noon;
+pub use self::calculations::solar::solar_position;
+pub use self::calculations::solar::sun_times;
+pub use self::calculations::solar::SolarPosition;
+pub use self::calculations::solar::SunHorizon;
+pub use self::calculations::solar::SunTimes;
+
+// Radiation
+pub use self::calculations::radiation::angstrom_solar_radiation;
+pub use self::calculations::radiation::clearness_index;
+pub use self::calculations::radiation::extraterrestrial_irradiance;
+pub use self::calculations::radiation::extraterrestrial_radiation;
+pub use self::calculations::radiation::fao_clear_sky_radiation;
+pub use self::calculations::radiation::haurwitz_clear_sky_irradiance;
+pub use self::calculations::radiation::ineichen_clear_sky_irradiance;
+pub use self::calculations::radiation::sunshine_duration;
+
+// Wind profile
+pub use self::calculations::wind_profile::common_power_law_wind_speed;
+pub use self::calculations::wind_profile::fao_wind_speed_at_2m;
+pub use self::calculations::wind_profile::log_profile_wind_speed;
+pub use self::calculations::wind_profile::power_law_wind_speed;
+pub use self::calculations::wind_profile::RoughnessLength;
+
 // Conversions
 // temperature
 pub use self::conversions::temperature::celsius_to_fahrenheit;
 pub use self::conversions::temperature::celsius_to_kelvin;
+pub use self::conversions::temperature::const_celsius_to_fahrenheit;
+pub use self::conversions::temperature::const_celsius_to_kelvin;
+pub use self::conversions::temperature::const_convert_temperature;
+pub use self::conversions::temperature::const_convert_temperature_difference;
+pub use self::conversions::temperature::const_fahrenheit_to_celsius;
+pub use self::conversions::temperature::const_fahrenheit_to_kelvin;
+pub use self::conversions::temperature::const_kelvin_to_celsius;
+pub use self::conversions::temperature::const_kelvin_to_fahrenheit;
+pub use self::conversions::temperature::convert_temperature;
+pub use self::conversions::temperature::convert_temperature_difference;
 pub use self::conversions::temperature::fahrenheit_to_celsius;
 pub use self::conversions::temperature::fahrenheit_to_kelvin;
 pub use self::conversions::temperature::kelvin_to_celsius;
 pub use self::conversions::temperature::kelvin_to_fahrenheit;
+pub use self::conversions::temperature::TemperatureScale;
 // pressure
+pub use self::conversions::pressure::const_convert_pressure;
+pub use self::conversions::pressure::const_hpa_to_inhg;
+pub use self::conversions::pressure::const_hpa_to_mmhg;
+pub use self::conversions::pressure::const_inhg_to_hpa;
+pub use self::conversions::pressure::const_mmhg_to_hpa;
+pub use self::conversions::pressure::convert_pressure;
 pub use self::conversions::pressure::hpa_to_inhg;
 pub use self::conversions::pressure::hpa_to_mmhg;
 pub use self::conversions::pressure::inhg_to_hpa;
 pub use self::conversions::pressure::mmhg_to_hpa;
+pub use self::conversions::pressure::PressureUnit;
 // speed
+pub use self::conversions::wind_speed::const_convert_speed;
+pub use self::conversions::wind_speed::const_kmph_to_knots;
+pub use self::conversions::wind_speed::const_kmph_to_mph;
+pub use self::conversions::wind_speed::const_kmph_to_mps;
+pub use self::conversions::wind_speed::const_knots_to_kmph;
+pub use self::conversions::wind_speed::const_knots_to_mph;
+pub use self::conversions::wind_speed::const_knots_to_mps;
+pub use self::conversions::wind_speed::const_mph_to_kmph;
+pub use self::conversions::wind_speed::const_mph_to_knots;
+pub use self::conversions::wind_speed::const_mph_to_mps;
+pub use self::conversions::wind_speed::const_mps_to_kmph;
+pub use self::conversions::wind_speed::const_mps_to_knots;
+pub use self::conversions::wind_speed::const_mps_to_mph;
+pub use self::conversions::wind_speed::convert_speed;
 pub use self::conversions::wind_speed::kmph_to_knots;
 pub use self::conversions::wind_speed::kmph_to_mph;
 pub use self::conversions::wind_speed::kmph_to_mps;
@@ -65,22 +175,56 @@ pub use self::conversions::wind_speed::mph_to_mps;
 pub use self::conversions::wind_speed::mps_to_kmph;
 pub use self::conversions::wind_speed::mps_to_knots;
 pub use self::conversions::wind_speed::mps_to_mph;
+pub use self::conversions::wind_speed::SpeedUnit;
+// length
+pub use self::conversions::length::const_convert_length;
+pub use self::conversions::length::convert_length;
+pub use self::conversions::length::LengthUnit;
+// precipitation
+pub use self::conversions::precipitation::const_convert_precipitation_rate;
+pub use self::conversions::precipitation::convert_precipitation_rate;
+pub use self::conversions::precipitation::rain_intensity;
+pub use self::conversions::precipitation::snow_intensity;
+pub use self::conversions::precipitation::snow_intensity_from_visibility;
+pub use self::conversions::precipitation::PrecipitationIntensity;
+pub use self::conversions::precipitation::PrecipitationRateUnit;
+// units
+pub use self::conversions::units::convert_unit;
+pub use self::conversions::units::convert_units;
+pub use self::conversions::units::Dimension;
+pub use self::conversions::units::Unit;
+pub use self::conversions::units::UnitError;
+// unit system
+pub use self::conversions::unit_system::ConvertedDerivedQuantities;
+pub use self::conversions::unit_system::ConvertedObservation;
+pub use self::conversions::unit_system::UnitSystem;
+// quantity
+pub use self::conversions::quantity::ParseQuantityError;
+pub use self::conversions::quantity::Quantity;
+pub use self::conversions::quantity::QuantityDisplay;
+pub use self::conversions::quantity::QuantityFormat;
+// direction
+pub use self::conversions::wind_direction::compass_to_degrees;
+pub use self::conversions::wind_direction::degrees_to_compass;
+pub use self::conversions::wind_direction::parse_wind_direction;
+pub use self::conversions::wind_direction::CompassLanguage;
+pub use self::conversions::wind_direction::CompassPoints;
+pub use self::conversions::wind_direction::ParseCompassError;
+pub use self::conversions::wind_direction::WindDirection;
 
 // TODO: Specific relative_humidity, Vapor pressure, Relative saturation
 // TODO: heat index variants: Steadman's Apparent Temperature, Australian Apparent Temperature, New Zealand Apparent Temperature
 // TODO: add accessivle constants for different measurements and values
 
 /// Rounds given value to 4 decimal places
-fn meteo_round(number: &f64) -> f64 {
-    (number * 10000.0).round() / 10000.0
+fn meteo_round<F: Float>(number: &F) -> F {
+    (*number * F::from_f64(10000.0)).round() / F::from_f64(10000.0)
 }
 
-// Function to calculate saturation vapor pressure using the Magnus-Tetens formula
-fn saturation_vapor_pressure(temperature_celsius: &f64) -> f64 {
-    let saturation_vapor_pressure = SATURATION_VAPOR_PRESSURE_REFERENCE
-        * HUMIDEX_CONSTANT_OFFSET.powf(
-            (LATENT_HEAT_OF_VAPORIZATION * temperature_celsius)
-                / (STANDARD_CONDENSATION_POINT + temperature_celsius),
-        );
-    saturation_vapor_pressure
+// Saturation vapor pressure in hPa over water using the Magnus-Tetens formula
+fn saturation_vapor_pressure<F: Float>(temperature_celsius: &F) -> F {
+    F::from_f64(SATURATION_VAPOR_PRESSURE_REFERENCE)
+        * ((F::from_f64(LATENT_HEAT_OF_VAPORIZATION) * *temperature_celsius)
+            / (F::from_f64(STANDARD_CONDENSATION_POINT) + *temperature_celsius))
+            .exp()
 }
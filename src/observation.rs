@@ -0,0 +1,170 @@
+use crate::calculations::absolute_humidity::absolute_humidity_from_vapor_pressure;
+use crate::calculations::humidex::humidex_from_dew_point;
+use crate::calculations::mixing_ratio::mixing_ratio_from_vapor_pressure;
+use crate::constants::constants::AVG_ATMOSPHERIC_PRESSURE;
+use crate::{
+    celsius_dew_point, celsius_heat_index, common_celsius_dew_point, convert_speed, meteo_round,
+    saturation_vapor_pressure, SpeedUnit, WindDirection,
+};
+
+/// Single set of measurements taken by a station at the same time.
+///
+/// Temperature and relative humidity are required, the other measurements are optional.
+/// Use [`Observation::derived`] to compute all derived quantities at once.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{Observation, WindDirection};
+///
+/// let observation = Observation::new(22.5, 62.4)
+///     .with_pressure(1005.0)
+///     .with_wind(3.2, WindDirection::Degrees(240.0))
+///     .with_solar_radiation(640.0);
+///
+/// assert_eq!(observation.pressure, Some(1005.0));
+/// assert_eq!(observation.wind_direction, Some(WindDirection::Degrees(240.0)));
+/// assert_eq!(observation.solar_radiation, Some(640.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct Observation {
+    /// Air temperature in degrees Celsius.
+    pub temperature: f64,
+    /// Relative humidity in percent.
+    pub relative_humidity: f64,
+    /// Station atmospheric pressure in hPa.
+    pub pressure: Option<f64>,
+    /// Wind speed in m/s.
+    pub wind_speed: Option<f64>,
+    /// Direction the wind blows from.
+    pub wind_direction: Option<WindDirection>,
+    /// Horizontal visibility in meters.
+    pub visibility: Option<f64>,
+    /// Global solar radiation in W/m².
+    pub solar_radiation: Option<f64>,
+}
+
+/// Quantities derived from an [`Observation`].
+///
+/// Quantities depending on atmospheric pressure use the measured pressure when available,
+/// otherwise the common atmospheric pressure, the same way as the `common_` functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct DerivedQuantities {
+    /// Saturation vapor pressure in hPa.
+    pub saturation_vapor_pressure: f64,
+    /// Actual vapor pressure in hPa.
+    pub vapor_pressure: f64,
+    /// Dew point in degrees Celsius.
+    pub dew_point: f64,
+    /// Heat index in degrees Celsius.
+    pub heat_index: f64,
+    /// Humidex in degrees Celsius.
+    pub humidex: f64,
+    /// Mixing ratio in kg/kg.
+    pub mixing_ratio: f64,
+    /// Absolute humidity in kg/m³.
+    pub absolute_humidity: f64,
+    /// Beaufort force of the wind, when wind speed is measured.
+    pub beaufort: Option<f64>,
+}
+
+impl Observation {
+    /// Creates observation from temperature in degrees Celsius and relative humidity in percent.
+    pub fn new(temperature: f64, relative_humidity: f64) -> Self {
+        Observation {
+            temperature,
+            relative_humidity,
+            pressure: None,
+            wind_speed: None,
+            wind_direction: None,
+            visibility: None,
+            solar_radiation: None,
+        }
+    }
+
+    /// Sets station atmospheric pressure in hPa.
+    pub fn with_pressure(mut self, pressure: f64) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    /// Sets wind speed in m/s and wind direction.
+    pub fn with_wind(mut self, speed: f64, direction: WindDirection) -> Self {
+        self.wind_speed = Some(speed);
+        self.wind_direction = Some(direction);
+        self
+    }
+
+    /// Sets horizontal visibility in meters.
+    pub fn with_visibility(mut self, visibility: f64) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Sets global solar radiation in W/m².
+    pub fn with_solar_radiation(mut self, radiation: f64) -> Self {
+        self.solar_radiation = Some(radiation);
+        self
+    }
+
+    /// Computes all derived quantities, computing the saturation vapor pressure, vapor pressure
+    /// and dew point once and deriving the other quantities from them.
+    ///
+    /// Results are identical to calling the individual functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::Observation;
+    ///
+    /// let derived = Observation::new(22.5, 62.4).with_pressure(1005.0).derived();
+    ///
+    /// assert_eq!(derived.saturation_vapor_pressure, 27.2111);
+    /// assert_eq!(derived.dew_point, meteo_tools::celsius_dew_point(&22.5, &62.4, &1005.0));
+    /// assert_eq!(derived.heat_index, meteo_tools::celsius_heat_index(&22.5, &62.4));
+    /// assert_eq!(derived.humidex, meteo_tools::celsius_humidex(&22.5, &62.4, &1005.0));
+    /// assert_eq!(derived.mixing_ratio, meteo_tools::celsius_mixing_ratio(&22.5, &62.4, &1005.0));
+    /// assert_eq!(derived.absolute_humidity, meteo_tools::celsius_absolute_humidity(&22.5, &62.4));
+    ///
+    /// let derived = Observation::new(31.0, 48.0).derived();
+    ///
+    /// assert_eq!(derived.dew_point, meteo_tools::common_celsius_dew_point(&31.0, &48.0));
+    /// assert_eq!(derived.humidex, meteo_tools::common_celsius_humidex(&31.0, &48.0));
+    /// assert_eq!(derived.mixing_ratio, meteo_tools::common_celsius_mixing_ratio(&31.0, &48.0));
+    /// assert_eq!(derived.absolute_humidity, meteo_tools::celsius_absolute_humidity(&31.0, &48.0));
+    /// ```
+    pub fn derived(&self) -> DerivedQuantities {
+        let temperature = self.temperature;
+        let relative_humidity = self.relative_humidity;
+        let saturation_vapor_pressure = saturation_vapor_pressure(&temperature);
+        let vapor_pressure = saturation_vapor_pressure * (relative_humidity / 100.0);
+
+        let (dew_point, pressure) = match self.pressure {
+            Some(pressure) => (
+                celsius_dew_point(&temperature, &relative_humidity, &pressure),
+                pressure,
+            ),
+            None => (
+                common_celsius_dew_point(&temperature, &relative_humidity),
+                AVG_ATMOSPHERIC_PRESSURE,
+            ),
+        };
+
+        DerivedQuantities {
+            saturation_vapor_pressure: meteo_round(&saturation_vapor_pressure),
+            vapor_pressure: meteo_round(&vapor_pressure),
+            dew_point,
+            heat_index: celsius_heat_index(&temperature, &relative_humidity),
+            humidex: humidex_from_dew_point(&temperature, &dew_point),
+            mixing_ratio: mixing_ratio_from_vapor_pressure(&vapor_pressure, &pressure),
+            absolute_humidity: absolute_humidity_from_vapor_pressure(&temperature, &vapor_pressure),
+            beaufort: self.wind_speed.map(|speed| {
+                convert_speed(&speed, SpeedUnit::MetersPerSecond, SpeedUnit::Beaufort)
+            }),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Decoding of coded weather reports, available with the `std` feature.
+//!
+//! Reports are parsed with [`FromStr`](core::str::FromStr) into structures holding the crate's [`Quantity`](crate::Quantity)
+//! and [`WindDirection`](crate::WindDirection) values, which can be converted to other units or fed to the calculations.
+//!
+//! # Examples
+//!
+//! ```
+//! use meteo_tools::reports::Metar;
+//! use meteo_tools::{PressureUnit, Unit};
+//!
+//! let metar: Metar = "METAR LKPR 121030Z 24008KT 9999 SCT030 18/09 Q1016 NOSIG=".parse().unwrap();
+//! let altimeter = metar.altimeter.unwrap();
+//!
+//! let inches = altimeter.convert_to(Unit::Pressure(PressureUnit::InchOfMercury)).unwrap();
+//!
+//! assert_eq!(inches.value, 30.0025);
+//! ```
+
+mod metar;
+mod synop;
+mod taf;
+
+pub use self::metar::CloudCover;
+pub use self::metar::CloudLayer;
+pub use self::metar::CloudType;
+pub use self::metar::EncodeReportError;
+pub use self::metar::Metar;
+pub use self::metar::MetarKind;
+pub use self::metar::ParseMetarError;
+pub use self::metar::RangeQualifier;
+pub use self::metar::RangeTendency;
+pub use self::metar::Remarks;
+pub use self::metar::ReportModifier;
+pub use self::metar::ReportTime;
+pub use self::metar::RunwayVisualRange;
+pub use self::metar::Visibility;
+pub use self::metar::Weather;
+pub use self::metar::WeatherDescriptor;
+pub use self::metar::WeatherIntensity;
+pub use self::metar::WeatherPhenomenon;
+pub use self::metar::Wind;
+pub use self::synop::ParseSynopError;
+pub use self::synop::Precipitation;
+pub use self::synop::PressureTendency;
+pub use self::synop::Synop;
+pub use self::synop::SynopCloudLayer;
+pub use self::synop::SynopClouds;
+pub use self::synop::WindIndicator;
+pub use self::taf::ChangeGroup;
+pub use self::taf::ChangeKind;
+pub use self::taf::Forecast;
+pub use self::taf::ForecastConditions;
+pub use self::taf::ForecastPeriod;
+pub use self::taf::ParseTafError;
+pub use self::taf::Taf;
+pub use self::taf::TafModifier;
+pub use self::taf::TemperatureForecast;
+pub use self::taf::ValidityPeriod;
+pub use self::taf::WindShear;
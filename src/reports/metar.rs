@@ -0,0 +1,1689 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{
+    compass_to_degrees, degrees_to_compass, mps_to_knots, CompassLanguage, CompassPoints,
+    Dimension, LengthUnit, Observation, PressureUnit, Quantity, Resolver, SpeedUnit,
+    TemperatureScale, Unit, Variable, WindDirection,
+};
+
+/// Routine or special aviation weather report.
+///
+/// Parsed with [`FromStr`] from the WMO FM 15 (METAR) and FM 16 (SPECI) code, including the US variants
+/// with statute miles, altimeter in inHg and `RMK` remarks. Values keep the units they were reported in,
+/// so a wind in knots is a [`Quantity`] in knots, use [`Quantity::convert_to`] to get other units.
+/// Groups missing in the report are `None` or empty.
+///
+/// Reports are printed with [`fmt::Display`] in the same code, applying the WMO rounding rules, so a report
+/// can be built from an [`Observation`] with [`Metar::from_observation`] and sent as text.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::reports::{CloudCover, Metar};
+/// use meteo_tools::{Quantity, SpeedUnit, Unit, WindDirection};
+///
+/// let report = "METAR KJFK 251651Z 31015G25KT 10SM FEW050 BKN250 M02/M11 A3012 RMK AO2 SLP201 T10221106";
+///
+/// let metar: Metar = report.parse().unwrap();
+/// let wind = metar.wind.unwrap();
+///
+/// assert_eq!(metar.station, "KJFK");
+/// assert_eq!(wind.direction, WindDirection::Degrees(310.0));
+/// assert_eq!(wind.gust, Some(Quantity::new(25.0, Unit::Speed(SpeedUnit::Knots))));
+/// assert_eq!(metar.clouds[1].cover, CloudCover::Broken);
+/// assert_eq!(metar.temperature.unwrap().value, -2.0);
+/// assert_eq!(metar.remarks.temperature.unwrap().value, -2.2);
+/// assert_eq!(metar.to_string(), report);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metar {
+    /// Routine or special report.
+    pub kind: MetarKind,
+    /// ICAO location indicator of the station.
+    pub station: String,
+    /// Day and time of the observation in UTC.
+    pub time: ReportTime,
+    /// Automated, corrected or missing report.
+    pub modifier: Option<ReportModifier>,
+    /// Surface wind.
+    pub wind: Option<Wind>,
+    /// Prevailing visibility.
+    pub visibility: Option<Visibility>,
+    /// Minimum visibility with its direction, when it differs from the prevailing visibility.
+    pub minimum_visibility: Option<Visibility>,
+    /// Visibility, weather and clouds are OK (CAVOK).
+    pub cavok: bool,
+    /// Runway visual ranges.
+    pub runway_visual_ranges: Vec<RunwayVisualRange>,
+    /// Present weather.
+    pub weather: Vec<Weather>,
+    /// Recent weather (RE groups).
+    pub recent_weather: Vec<Weather>,
+    /// Other supplementary groups, undecoded: wind shear (`WS R04`), sea surface (`W15/S2`)
+    /// and runway state (`R25L/290050`).
+    pub supplementary: Vec<String>,
+    /// Cloud layers, vertical visibility or the reason there are no clouds reported.
+    pub clouds: Vec<CloudLayer>,
+    /// Air temperature in degrees Celsius.
+    pub temperature: Option<Quantity>,
+    /// Dew point in degrees Celsius.
+    pub dew_point: Option<Quantity>,
+    /// Altimeter setting, QNH in hPa or A group in inHg.
+    pub altimeter: Option<Quantity>,
+    /// Trend forecast appended to the report (NOSIG, BECMG and TEMPO groups), undecoded.
+    pub trend: Option<String>,
+    /// Remarks following `RMK`.
+    pub remarks: Remarks,
+}
+
+/// Kind of an aviation weather report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MetarKind {
+    /// Routine report (METAR).
+    #[default]
+    Metar,
+    /// Special report issued on significant changes (SPECI).
+    Speci,
+}
+
+/// Day of the month and time in UTC of a report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportTime {
+    /// Day of the month, 1-31.
+    pub day: u8,
+    /// Hour in UTC, 0-23, or 24 for the end of the day in TAF periods.
+    pub hour: u8,
+    /// Minute, 0-59.
+    pub minute: u8,
+}
+
+/// Modifier following the report time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReportModifier {
+    /// Fully automated report (AUTO).
+    Auto,
+    /// Corrected report (COR).
+    Corrected,
+    /// Missing report (NIL).
+    Nil,
+}
+
+/// Surface wind of a report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wind {
+    /// Mean direction, variable or calm.
+    pub direction: WindDirection,
+    /// Mean speed in the reported unit.
+    pub speed: Quantity,
+    /// Mean speed is more than the reported value (P prefix, as in `P49MPS`).
+    pub speed_above: bool,
+    /// Maximum gust speed in the reported unit.
+    pub gust: Option<Quantity>,
+    /// Gust speed is more than the reported value (P prefix, as in `GP99KT`).
+    pub gust_above: bool,
+    /// Extreme directions in degrees clockwise, when the direction varies by 60° or more.
+    pub variation: Option<(f64, f64)>,
+}
+
+/// Whether a reported distance is a bound rather than an exact value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeQualifier {
+    /// Distance is less than the reported value (M prefix).
+    Below,
+    /// Distance is more than the reported value (P prefix), also 10 km or more reported as 9999.
+    Above,
+}
+
+/// Visibility or runway visual range distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Visibility {
+    /// Distance in meters, feet or statute miles, as reported.
+    pub distance: Quantity,
+    /// Whether the distance is a bound.
+    pub qualifier: Option<RangeQualifier>,
+    /// Direction of the visibility in degrees, for minimum visibility.
+    pub direction: Option<f64>,
+}
+
+/// Runway visual range of one runway.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunwayVisualRange {
+    /// Runway designator, such as `24L`.
+    pub runway: String,
+    /// Range, the lower bound when the range is variable.
+    pub range: Visibility,
+    /// Upper bound when the range is variable.
+    pub maximum: Option<Visibility>,
+    /// Tendency of the range.
+    pub tendency: Option<RangeTendency>,
+}
+
+/// Tendency of a runway visual range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeTendency {
+    /// Increasing (U).
+    Up,
+    /// Decreasing (D).
+    Down,
+    /// No distinct change (N).
+    NoChange,
+}
+
+/// Present or recent weather group, such as `-SHRA` or `VCTS`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Weather {
+    /// Intensity of precipitation.
+    pub intensity: WeatherIntensity,
+    /// Weather is in the vicinity (VC), not at the station.
+    pub in_vicinity: bool,
+    /// Descriptor characterising the phenomena.
+    pub descriptor: Option<WeatherDescriptor>,
+    /// Precipitation, obscuration and other phenomena.
+    pub phenomena: Vec<WeatherPhenomenon>,
+}
+
+/// Intensity of a weather group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeatherIntensity {
+    /// Light (-).
+    Light,
+    /// Moderate, without a sign.
+    Moderate,
+    /// Heavy (+).
+    Heavy,
+}
+
+/// Descriptor of a weather group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeatherDescriptor {
+    /// Shallow (MI).
+    Shallow,
+    /// Partial (PR).
+    Partial,
+    /// Patches (BC).
+    Patches,
+    /// Low drifting (DR).
+    LowDrifting,
+    /// Blowing (BL).
+    Blowing,
+    /// Showers (SH).
+    Showers,
+    /// Thunderstorm (TS).
+    Thunderstorm,
+    /// Freezing (FZ).
+    Freezing,
+}
+
+/// Weather phenomenon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeatherPhenomenon {
+    /// Drizzle (DZ).
+    Drizzle,
+    /// Rain (RA).
+    Rain,
+    /// Snow (SN).
+    Snow,
+    /// Snow grains (SG).
+    SnowGrains,
+    /// Ice crystals (IC).
+    IceCrystals,
+    /// Ice pellets (PL).
+    IcePellets,
+    /// Hail (GR).
+    Hail,
+    /// Small hail or snow pellets (GS).
+    SmallHail,
+    /// Unknown precipitation (UP).
+    UnknownPrecipitation,
+    /// Mist (BR).
+    Mist,
+    /// Fog (FG).
+    Fog,
+    /// Smoke (FU).
+    Smoke,
+    /// Volcanic ash (VA).
+    VolcanicAsh,
+    /// Widespread dust (DU).
+    Dust,
+    /// Sand (SA).
+    Sand,
+    /// Haze (HZ).
+    Haze,
+    /// Spray (PY).
+    Spray,
+    /// Dust or sand whirls (PO).
+    DustWhirls,
+    /// Squalls (SQ).
+    Squalls,
+    /// Funnel cloud, tornado or waterspout (FC).
+    FunnelCloud,
+    /// Sandstorm (SS).
+    Sandstorm,
+    /// Duststorm (DS).
+    Duststorm,
+}
+
+const WEATHER_DESCRIPTORS: [(&str, WeatherDescriptor); 8] = [
+    ("MI", WeatherDescriptor::Shallow),
+    ("PR", WeatherDescriptor::Partial),
+    ("BC", WeatherDescriptor::Patches),
+    ("DR", WeatherDescriptor::LowDrifting),
+    ("BL", WeatherDescriptor::Blowing),
+    ("SH", WeatherDescriptor::Showers),
+    ("TS", WeatherDescriptor::Thunderstorm),
+    ("FZ", WeatherDescriptor::Freezing),
+];
+
+const WEATHER_PHENOMENA: [(&str, WeatherPhenomenon); 22] = [
+    ("DZ", WeatherPhenomenon::Drizzle),
+    ("RA", WeatherPhenomenon::Rain),
+    ("SN", WeatherPhenomenon::Snow),
+    ("SG", WeatherPhenomenon::SnowGrains),
+    ("IC", WeatherPhenomenon::IceCrystals),
+    ("PL", WeatherPhenomenon::IcePellets),
+    ("GR", WeatherPhenomenon::Hail),
+    ("GS", WeatherPhenomenon::SmallHail),
+    ("UP", WeatherPhenomenon::UnknownPrecipitation),
+    ("BR", WeatherPhenomenon::Mist),
+    ("FG", WeatherPhenomenon::Fog),
+    ("FU", WeatherPhenomenon::Smoke),
+    ("VA", WeatherPhenomenon::VolcanicAsh),
+    ("DU", WeatherPhenomenon::Dust),
+    ("SA", WeatherPhenomenon::Sand),
+    ("HZ", WeatherPhenomenon::Haze),
+    ("PY", WeatherPhenomenon::Spray),
+    ("PO", WeatherPhenomenon::DustWhirls),
+    ("SQ", WeatherPhenomenon::Squalls),
+    ("FC", WeatherPhenomenon::FunnelCloud),
+    ("SS", WeatherPhenomenon::Sandstorm),
+    ("DS", WeatherPhenomenon::Duststorm),
+];
+
+/// Amount of a cloud layer, or the reason no layer is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloudCover {
+    /// Sky clear (SKC).
+    SkyClear,
+    /// No clouds below 12,000 ft detected by an automated station (CLR).
+    Clear,
+    /// No significant cloud (NSC).
+    NoSignificantCloud,
+    /// No cloud detected by an automated station (NCD).
+    NoCloudDetected,
+    /// Few, 1-2 oktas (FEW).
+    Few,
+    /// Scattered, 3-4 oktas (SCT).
+    Scattered,
+    /// Broken, 5-7 oktas (BKN).
+    Broken,
+    /// Overcast, 8 oktas (OVC).
+    Overcast,
+    /// Sky obscured, the base is the vertical visibility (VV).
+    VerticalVisibility,
+    /// Amount not observed by an automated station (`///`), as in `//////CB`.
+    NotObserved,
+}
+
+const CLOUD_COVERS: [(&str, CloudCover); 10] = [
+    ("SKC", CloudCover::SkyClear),
+    ("CLR", CloudCover::Clear),
+    ("NSC", CloudCover::NoSignificantCloud),
+    ("NCD", CloudCover::NoCloudDetected),
+    ("FEW", CloudCover::Few),
+    ("SCT", CloudCover::Scattered),
+    ("BKN", CloudCover::Broken),
+    ("OVC", CloudCover::Overcast),
+    ("VV", CloudCover::VerticalVisibility),
+    ("///", CloudCover::NotObserved),
+];
+
+/// Convective cloud type of a layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CloudType {
+    /// Cumulonimbus (CB).
+    Cumulonimbus,
+    /// Towering cumulus (TCU).
+    ToweringCumulus,
+}
+
+/// Cloud layer of a report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloudLayer {
+    /// Amount of the layer.
+    pub cover: CloudCover,
+    /// Height of the base above ground in feet, `None` when not measured or for sky clear codes.
+    pub base: Option<Quantity>,
+    /// Convective cloud type.
+    pub cloud_type: Option<CloudType>,
+}
+
+/// Decoded remarks of a report. Only some groups are decoded, all remarks are kept as text.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Remarks {
+    /// Sea level pressure in hPa (SLP group).
+    pub sea_level_pressure: Option<Quantity>,
+    /// Air temperature to tenths of degree Celsius (T group).
+    pub temperature: Option<Quantity>,
+    /// Dew point to tenths of degree Celsius (T group).
+    pub dew_point: Option<Quantity>,
+    /// All remarks as reported.
+    pub text: String,
+}
+
+/// Error returned when a METAR or SPECI report cannot be parsed. Carries the offending group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMetarError {
+    /// Report is empty.
+    Empty,
+    /// Report ends before the station and time.
+    Incomplete,
+    /// Station is not a four character ICAO location indicator.
+    InvalidStation(String),
+    /// Time group is not `ddhhmmZ` or is out of range.
+    InvalidTime(String),
+    /// Wind or wind variation group is malformed.
+    InvalidWind(String),
+    /// Visibility group is malformed.
+    InvalidVisibility(String),
+    /// Runway visual range group is malformed.
+    InvalidRunwayVisualRange(String),
+    /// Weather group contains unknown descriptor or phenomenon.
+    InvalidWeather(String),
+    /// Cloud group is malformed.
+    InvalidCloud(String),
+    /// Temperature and dew point group is malformed.
+    InvalidTemperature(String),
+    /// Altimeter group is malformed.
+    InvalidPressure(String),
+    /// Decoded remark group (SLP, T) is malformed.
+    InvalidRemark(String),
+    /// Group is not recognised.
+    UnexpectedGroup(String),
+}
+
+impl fmt::Display for ParseMetarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, group) = match self {
+            ParseMetarError::Empty => return f.write_str("empty report"),
+            ParseMetarError::Incomplete => return f.write_str("report has no station or time"),
+            ParseMetarError::InvalidStation(group) => ("station", group),
+            ParseMetarError::InvalidTime(group) => ("time", group),
+            ParseMetarError::InvalidWind(group) => ("wind", group),
+            ParseMetarError::InvalidVisibility(group) => ("visibility", group),
+            ParseMetarError::InvalidRunwayVisualRange(group) => ("runway visual range", group),
+            ParseMetarError::InvalidWeather(group) => ("weather", group),
+            ParseMetarError::InvalidCloud(group) => ("cloud", group),
+            ParseMetarError::InvalidTemperature(group) => ("temperature", group),
+            ParseMetarError::InvalidPressure(group) => ("pressure", group),
+            ParseMetarError::InvalidRemark(group) => ("remark", group),
+            ParseMetarError::UnexpectedGroup(group) => {
+                return write!(f, "unexpected group `{}`", group)
+            }
+        };
+        write!(f, "invalid {} group `{}`", kind, group)
+    }
+}
+
+impl std::error::Error for ParseMetarError {}
+
+/// Error returned when a report cannot be encoded. Carries the name of the offending value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeReportError {
+    /// Value is not in a unit of the dimension the code requires, such as a temperature in hPa.
+    InvalidUnit {
+        /// Name of the value, such as `dew point`.
+        value: &'static str,
+        /// Dimension the code requires.
+        expected: Dimension,
+    },
+}
+
+impl fmt::Display for EncodeReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeReportError::InvalidUnit { value, expected } => {
+                write!(f, "{} is not in a {} unit", value, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeReportError {}
+
+impl Metar {
+    /// Builds an automated report (AUTO) of given station and time from an [`Observation`].
+    ///
+    /// Wind speed is converted to knots, dew point is derived from the relative humidity and
+    /// the temperature and dew point to tenths are kept for the T group of remarks. The altimeter setting is
+    /// not derived from the station pressure, set [`Metar::altimeter`] to the QNH when it is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{Metar, ReportTime};
+    /// use meteo_tools::{Observation, PressureUnit, Quantity, Unit, WindDirection};
+    ///
+    /// let observation = Observation::new(-2.2, 49.5)
+    ///     .with_wind(7.7, WindDirection::Degrees(307.0))
+    ///     .with_visibility(16093.0);
+    /// let time = ReportTime { day: 25, hour: 16, minute: 51 };
+    ///
+    /// let mut metar = Metar::from_observation("KJFK", time, &observation);
+    /// metar.altimeter = Some(Quantity::new(30.128, Unit::Pressure(PressureUnit::InchOfMercury)));
+    ///
+    /// assert_eq!(metar.to_string(), "METAR KJFK 251651Z AUTO 31015KT 9999 M02/M11 A3012 RMK T10221113");
+    /// ```
+    pub fn from_observation(station: &str, time: ReportTime, observation: &Observation) -> Self {
+        let wind = observation.wind_speed.map(|speed| Wind {
+            direction: observation
+                .wind_direction
+                .unwrap_or(WindDirection::Variable),
+            speed: Quantity::new(mps_to_knots(&speed), Unit::Speed(SpeedUnit::Knots)),
+            speed_above: false,
+            gust: None,
+            gust_above: false,
+            variation: None,
+        });
+        let visibility = observation.visibility.map(|visibility| Visibility {
+            distance: Quantity::new(visibility, Unit::Length(LengthUnit::Meter)),
+            qualifier: None,
+            direction: None,
+        });
+        let temperature = celsius(observation.temperature);
+        let dew_point = celsius(observation.derived().dew_point);
+        Metar {
+            station: station.to_string(),
+            time,
+            modifier: Some(ReportModifier::Auto),
+            wind,
+            visibility,
+            temperature: Some(temperature),
+            dew_point: Some(dew_point),
+            remarks: Remarks {
+                temperature: Some(temperature),
+                dew_point: Some(dew_point),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Encodes the report like [`fmt::Display`], but fails when a value cannot be converted to the unit of its group
+    /// instead of printing the group as missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{EncodeReportError, Metar};
+    /// use meteo_tools::{Dimension, PressureUnit, Quantity, Unit};
+    ///
+    /// let mut metar: Metar = "LKPR 121030Z 24008KT 9999 SCT030 18/09 Q1016".parse().unwrap();
+    /// metar.dew_point = Some(Quantity::new(1016.0, Unit::Pressure(PressureUnit::Hectopascal)));
+    ///
+    /// let error = EncodeReportError::InvalidUnit { value: "dew point", expected: Dimension::Temperature };
+    ///
+    /// assert_eq!(metar.encode(), Err(error));
+    /// assert_eq!(metar.to_string(), "METAR LKPR 121030Z 24008KT 9999 SCT030 18/// Q1016");
+    /// ```
+    pub fn encode(&self) -> Result<String, EncodeReportError> {
+        if let Some(wind) = &self.wind {
+            check_unit(&wind.speed, Dimension::Speed, "wind speed")?;
+            if let Some(gust) = &wind.gust {
+                check_unit(gust, Dimension::Speed, "gust speed")?;
+            }
+        }
+        for visibility in self.visibility.iter().chain(&self.minimum_visibility) {
+            check_unit(&visibility.distance, Dimension::Length, "visibility")?;
+        }
+        for range in &self.runway_visual_ranges {
+            for range in std::iter::once(&range.range).chain(&range.maximum) {
+                check_unit(&range.distance, Dimension::Length, "runway visual range")?;
+            }
+        }
+        for base in self.clouds.iter().filter_map(|layer| layer.base.as_ref()) {
+            check_unit(base, Dimension::Length, "cloud base")?;
+        }
+        let temperatures = [
+            (&self.temperature, "temperature"),
+            (&self.dew_point, "dew point"),
+            (&self.remarks.temperature, "temperature"),
+            (&self.remarks.dew_point, "dew point"),
+        ];
+        for (temperature, value) in temperatures {
+            if let Some(temperature) = temperature {
+                check_unit(temperature, Dimension::Temperature, value)?;
+            }
+        }
+        for pressure in [&self.altimeter, &self.remarks.sea_level_pressure]
+            .into_iter()
+            .flatten()
+        {
+            check_unit(pressure, Dimension::Pressure, "pressure")?;
+        }
+        Ok(self.to_string())
+    }
+
+    /// Converts the report to an [`Observation`], when both temperature and dew point are reported.
+    ///
+    /// Temperatures to tenths from the remarks are preferred. Relative humidity is derived from the dew point,
+    /// wind speed is converted to m/s and visibility to meters. Pressure is left out, as the altimeter setting
+    /// is reduced to sea level, while the observation takes the station pressure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::Metar;
+    /// use meteo_tools::WindDirection;
+    ///
+    /// let metar: Metar = "LKPR 121030Z 24008KT 9999 SCT030 18/09 Q1016 NOSIG".parse().unwrap();
+    ///
+    /// let observation = metar.observation().unwrap();
+    ///
+    /// assert_eq!(observation.temperature, 18.0);
+    /// assert_eq!(observation.relative_humidity, 55.6724);
+    /// assert_eq!(observation.wind_speed, Some(4.1156));
+    /// assert_eq!(observation.wind_direction, Some(WindDirection::Degrees(240.0)));
+    /// assert_eq!(observation.visibility, Some(10000.0));
+    /// ```
+    pub fn observation(&self) -> Option<Observation> {
+        let temperature = self.remarks.temperature.or(self.temperature)?.value;
+        let dew_point = self.remarks.dew_point.or(self.dew_point)?.value;
+        let relative_humidity = Resolver::new()
+            .with(Variable::Temperature, temperature)
+            .with(Variable::DewPoint, dew_point)
+            .resolve(Variable::RelativeHumidity)
+            .ok()?;
+        let mut observation = Observation::new(temperature, relative_humidity);
+        if let Some(wind) = self.wind {
+            let speed = wind
+                .speed
+                .convert_to(Unit::Speed(SpeedUnit::MetersPerSecond))
+                .ok()?;
+            observation = observation.with_wind(speed.value, wind.direction);
+        }
+        if let Some(visibility) = self.visibility {
+            let distance = visibility
+                .distance
+                .convert_to(Unit::Length(LengthUnit::Meter))
+                .ok()?;
+            observation = observation.with_visibility(distance.value);
+        }
+        Some(observation)
+    }
+}
+
+impl FromStr for Metar {
+    type Err = ParseMetarError;
+
+    /// Parses a report, optionally starting with `METAR` or `SPECI` and ending with `=`.
+    ///
+    /// Groups missing in automated reports (`////`, `RE//`) are skipped. Wind shear, sea surface and
+    /// runway state groups are kept undecoded in [`Metar::supplementary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{CloudCover, CloudType, Metar};
+    ///
+    /// let report = "METAR UUEE 121030Z 270P49MPS 9999 //////CB M05/M08 Q1020 WS R06 R24L/290050 RMK SLP///";
+    ///
+    /// let metar: Metar = report.parse().unwrap();
+    ///
+    /// assert!(metar.wind.unwrap().speed_above);
+    /// assert_eq!(metar.clouds[0].cover, CloudCover::NotObserved);
+    /// assert_eq!(metar.clouds[0].cloud_type, Some(CloudType::Cumulonimbus));
+    /// assert_eq!(metar.supplementary, ["WS R06", "R24L/290050"]);
+    /// assert_eq!(metar.to_string(), report);
+    /// ```
+    fn from_str(report: &str) -> Result<Self, Self::Err> {
+        let report = report.trim().trim_end_matches('=');
+        let groups: Vec<&str> = report.split_whitespace().collect();
+        if groups.is_empty() {
+            return Err(ParseMetarError::Empty);
+        }
+        let (body, remarks) = match groups.iter().position(|group| *group == "RMK") {
+            Some(index) => (&groups[..index], &groups[index + 1..]),
+            None => (&groups[..], &groups[groups.len()..]),
+        };
+        let (body, trend) = match body.iter().position(|group| is_trend(group)) {
+            Some(index) => (&body[..index], Some(body[index..].join(" "))),
+            None => (body, None),
+        };
+
+        let mut metar = Metar {
+            trend,
+            remarks: parse_remarks(remarks)?,
+            ..Default::default()
+        };
+        let mut groups = body.iter().copied().peekable();
+        match groups.peek() {
+            Some(&"METAR") => {
+                groups.next();
+            }
+            Some(&"SPECI") => {
+                metar.kind = MetarKind::Speci;
+                groups.next();
+            }
+            _ => {}
+        }
+        if groups.next_if_eq(&"COR").is_some() {
+            metar.modifier = Some(ReportModifier::Corrected);
+        }
+        let station = groups.next().ok_or(ParseMetarError::Incomplete)?;
+        metar.station = parse_station(station)
+            .ok_or_else(|| ParseMetarError::InvalidStation(station.to_string()))?;
+        let time = groups.next().ok_or(ParseMetarError::Incomplete)?;
+        metar.time =
+            parse_time(time).ok_or_else(|| ParseMetarError::InvalidTime(time.to_string()))?;
+
+        while let Some(group) = groups.next() {
+            match group {
+                "AUTO" => metar.modifier = Some(ReportModifier::Auto),
+                "COR" => metar.modifier = Some(ReportModifier::Corrected),
+                "NIL" => metar.modifier = Some(ReportModifier::Nil),
+                "CAVOK" => metar.cavok = true,
+                // Wind shear on a runway (`WS R04`, `WS RWY04`) or all runways (`WS ALL RWY`)
+                "WS" => {
+                    let mut wind_shear = String::from(group);
+                    let runways = match groups.next_if_eq(&"ALL") {
+                        Some(all) => [Some(all), groups.next_if_eq(&"RWY")],
+                        None => [groups.next_if(|next| next.starts_with('R')), None],
+                    };
+                    for runway in runways.into_iter().flatten() {
+                        wind_shear.push(' ');
+                        wind_shear.push_str(runway);
+                    }
+                    metar.supplementary.push(wind_shear);
+                }
+                // Recent weather not observed by an automated station
+                "RE//" => {}
+                _ if group.bytes().all(|byte| byte == b'/') => {}
+                _ => {
+                    // Whole statute miles are a separate group before the fraction, as in `1 1/2SM`
+                    let next_is_fraction = groups
+                        .peek()
+                        .is_some_and(|next| next.ends_with("SM") && next.contains('/'));
+                    if group.len() <= 2 && parse_number(group).is_some() && next_is_fraction {
+                        let fraction = groups.next().unwrap_or_default();
+                        let combined = format!("{} {}", group, fraction);
+                        metar.visibility = Some(
+                            parse_statute_visibility(&combined)
+                                .ok_or(ParseMetarError::InvalidVisibility(combined))?,
+                        );
+                    } else {
+                        parse_group(&mut metar, group)?;
+                    }
+                }
+            }
+        }
+        Ok(metar)
+    }
+}
+
+impl fmt::Display for Metar {
+    /// Prints the report, `CAVOK` replaces visibility, runway visual ranges, weather and clouds.
+    ///
+    /// Temperatures are rounded to whole degrees with halves rounded up and negative values, including
+    /// rounded zero, prefixed with `M`. Altimeter setting is rounded down to hundredths of inHg when given in inHg,
+    /// otherwise to whole hPa. Wind in other units than knots, m/s and km/h is converted to knots.
+    /// Values failing to convert to the unit of their group, such as a temperature in hPa, are printed as missing,
+    /// use [`Metar::encode`] to get an error instead.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            MetarKind::Metar => "METAR",
+            MetarKind::Speci => "SPECI",
+        };
+        write!(f, "{} {} {}", kind, self.station, self.time)?;
+        match self.modifier {
+            Some(ReportModifier::Auto) => f.write_str(" AUTO")?,
+            Some(ReportModifier::Corrected) => f.write_str(" COR")?,
+            Some(ReportModifier::Nil) => return f.write_str(" NIL"),
+            None => {}
+        }
+        if let Some(wind) = &self.wind {
+            write!(f, " {}", wind)?;
+        }
+        if self.cavok {
+            f.write_str(" CAVOK")?;
+        } else {
+            for visibility in self.visibility.iter().chain(&self.minimum_visibility) {
+                write!(f, " {}", visibility)?;
+            }
+            for range in &self.runway_visual_ranges {
+                write!(f, " {}", range)?;
+            }
+            for weather in &self.weather {
+                write!(f, " {}", weather)?;
+            }
+            for layer in &self.clouds {
+                write!(f, " {}", layer)?;
+            }
+        }
+        if let Some(temperature) = &self.temperature {
+            f.write_str(" ")?;
+            write_temperature(f, temperature)?;
+            f.write_str("/")?;
+            if let Some(dew_point) = &self.dew_point {
+                write_temperature(f, dew_point)?;
+            }
+        }
+        if let Some(altimeter) = &self.altimeter {
+            // Altimeter settings are rounded down, tolerance covers binary representation of e.g. 29.92
+            match altimeter.unit {
+                Unit::Pressure(PressureUnit::InchOfMercury) => write!(
+                    f,
+                    " A{:04}",
+                    (altimeter.value * 100.0 + 1e-6).floor() as u32
+                )?,
+                _ => match value_in(altimeter, Unit::Pressure(PressureUnit::Hectopascal)) {
+                    Some(hectopascals) => {
+                        write!(f, " Q{:04}", (hectopascals + 1e-6).floor() as u32)?
+                    }
+                    None => f.write_str(" Q////")?,
+                },
+            }
+        }
+        for weather in &self.recent_weather {
+            write!(f, " RE{}", weather)?;
+        }
+        for group in &self.supplementary {
+            write!(f, " {}", group)?;
+        }
+        if let Some(trend) = &self.trend {
+            write!(f, " {}", trend)?;
+        }
+        let remarks = self.remarks.to_string();
+        if !remarks.is_empty() {
+            write!(f, " RMK {}", remarks)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ReportTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}{:02}Z", self.day, self.hour, self.minute)
+    }
+}
+
+impl fmt::Display for Wind {
+    /// Prints direction rounded to tens of degrees and speeds rounded to whole units,
+    /// a wind rounded to zero speed is printed as calm.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (unit, suffix) = WIND_UNITS
+            .iter()
+            .find(|(_, unit)| self.speed.unit == Unit::Speed(*unit))
+            .map_or((SpeedUnit::Knots, "KT"), |(suffix, unit)| (*unit, *suffix));
+        let Some(speed) = value_in(&self.speed, Unit::Speed(unit)) else {
+            return write!(f, "/////{}", suffix);
+        };
+        let speed = speed.round() as u32;
+        match self.direction {
+            _ if speed == 0 => f.write_str("000")?,
+            WindDirection::Degrees(degrees) => write!(f, "{:03}", round_direction(degrees))?,
+            WindDirection::Variable => f.write_str("VRB")?,
+            WindDirection::Calm => f.write_str("000")?,
+        }
+        if self.speed_above {
+            f.write_str("P")?;
+        }
+        write!(f, "{:02}", speed)?;
+        if let Some(gust) = &self.gust {
+            f.write_str("G")?;
+            if self.gust_above {
+                f.write_str("P")?;
+            }
+            match value_in(gust, Unit::Speed(unit)) {
+                Some(gust) => write!(f, "{:02}", gust.round() as u32)?,
+                None => f.write_str("//")?,
+            }
+        }
+        f.write_str(suffix)?;
+        if let Some((from, to)) = self.variation {
+            write!(
+                f,
+                " {:03}V{:03}",
+                round_direction(from),
+                round_direction(to)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Visibility {
+    /// Prints statute miles with fractions down to sixteenths, other units in meters
+    /// rounded down to the reporting steps of 50 m, 100 m and 1000 m, 10 km or more as `9999`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.distance.unit == Unit::Length(LengthUnit::StatuteMile) {
+            return write_statute_miles(f, self);
+        }
+        let Some(meters) = value_in(&self.distance, Unit::Length(LengthUnit::Meter)) else {
+            return f.write_str("////");
+        };
+        let step = match meters {
+            meters if meters < 800.0 => 50.0,
+            meters if meters < 5000.0 => 100.0,
+            _ => 1000.0,
+        };
+        let meters = ((meters / step).floor() * step) as u32;
+        if meters >= 10000 {
+            f.write_str("9999")?;
+        } else {
+            write!(f, "{:04}", meters)?;
+        }
+        let compass = self.direction.and_then(|direction| {
+            degrees_to_compass(&direction, CompassPoints::Eight, CompassLanguage::English)
+        });
+        if let Some(compass) = compass {
+            f.write_str(compass)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RunwayVisualRange {
+    /// Prints the range in feet when reported in feet, otherwise in meters.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let in_feet = self.range.distance.unit == Unit::Length(LengthUnit::Foot);
+        let unit = Unit::Length(if in_feet {
+            LengthUnit::Foot
+        } else {
+            LengthUnit::Meter
+        });
+        write!(f, "R{}/", self.runway)?;
+        write_range(f, &self.range, unit)?;
+        if let Some(maximum) = &self.maximum {
+            f.write_str("V")?;
+            write_range(f, maximum, unit)?;
+        }
+        if in_feet {
+            f.write_str("FT")?;
+        }
+        let tendency = match self.tendency {
+            Some(RangeTendency::Up) => "U",
+            Some(RangeTendency::Down) => "D",
+            Some(RangeTendency::NoChange) => "N",
+            None => return Ok(()),
+        };
+        if in_feet {
+            f.write_str("/")?;
+        }
+        f.write_str(tendency)
+    }
+}
+
+impl fmt::Display for Weather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.intensity {
+            WeatherIntensity::Light => f.write_str("-")?,
+            WeatherIntensity::Moderate => {}
+            WeatherIntensity::Heavy => f.write_str("+")?,
+        }
+        if self.in_vicinity {
+            f.write_str("VC")?;
+        }
+        if let Some(descriptor) = &self.descriptor {
+            f.write_str(code(&WEATHER_DESCRIPTORS, descriptor))?;
+        }
+        for phenomenon in &self.phenomena {
+            f.write_str(code(&WEATHER_PHENOMENA, phenomenon))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for CloudLayer {
+    /// Prints base in hundreds of feet, `///` when not measured.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(code(&CLOUD_COVERS, &self.cover))?;
+        if !self.cover.has_base() {
+            return Ok(());
+        }
+        match &self.base {
+            Some(base) => match value_in(base, Unit::Length(LengthUnit::Foot)) {
+                Some(feet) => write!(f, "{:03}", (feet / 100.0).round() as u32)?,
+                None => f.write_str("///")?,
+            },
+            None => f.write_str("///")?,
+        }
+        match self.cloud_type {
+            Some(CloudType::Cumulonimbus) => f.write_str("CB"),
+            Some(CloudType::ToweringCumulus) => f.write_str("TCU"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Remarks {
+    /// Prints the text with its SLP and T groups replaced by the decoded values.
+    /// Decoded values missing in the text are appended.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut pressure_written, mut temperatures_written) = (false, false);
+        let mut separator = "";
+        for group in self.text.split_whitespace() {
+            let written = match decoded_remark(group) {
+                Some(DecodedRemark::SeaLevelPressure) => {
+                    pressure_written = self.write_sea_level_pressure(f, separator)?;
+                    pressure_written
+                }
+                Some(DecodedRemark::Temperatures) => {
+                    temperatures_written = self.write_temperatures(f, separator)?;
+                    temperatures_written
+                }
+                None => {
+                    write!(f, "{}{}", separator, group)?;
+                    true
+                }
+            };
+            if written {
+                separator = " ";
+            }
+        }
+        if !pressure_written && self.write_sea_level_pressure(f, separator)? {
+            separator = " ";
+        }
+        if !temperatures_written {
+            self.write_temperatures(f, separator)?;
+        }
+        Ok(())
+    }
+}
+
+impl Remarks {
+    // Writes SLP group when sea level pressure is known, returns whether it was written
+    fn write_sea_level_pressure(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        separator: &str,
+    ) -> Result<bool, fmt::Error> {
+        let Some(hectopascals) = self
+            .sea_level_pressure
+            .and_then(|pressure| value_in(&pressure, Unit::Pressure(PressureUnit::Hectopascal)))
+        else {
+            return Ok(false);
+        };
+        let tenths = (hectopascals * 10.0).round() as u32 % 1000;
+        write!(f, "{}SLP{:03}", separator, tenths)?;
+        Ok(true)
+    }
+
+    // Writes T group when both temperature and dew point are known, returns whether it was written
+    fn write_temperatures(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        separator: &str,
+    ) -> Result<bool, fmt::Error> {
+        let (Some(temperature), Some(dew_point)) = (&self.temperature, &self.dew_point) else {
+            return Ok(false);
+        };
+        if [temperature, dew_point]
+            .iter()
+            .any(|temperature| temperature.unit.dimension() != Dimension::Temperature)
+        {
+            return Ok(false);
+        }
+        write!(f, "{}T", separator)?;
+        write_precise_temperature(f, temperature)?;
+        write_precise_temperature(f, dew_point)?;
+        Ok(true)
+    }
+}
+
+impl CloudCover {
+    // Sky clear codes have no base
+    fn has_base(&self) -> bool {
+        !matches!(
+            self,
+            CloudCover::SkyClear
+                | CloudCover::Clear
+                | CloudCover::NoSignificantCloud
+                | CloudCover::NoCloudDetected
+        )
+    }
+}
+
+// Code of a value in a code table, all variants are listed in the tables
+fn code<T: PartialEq>(table: &[(&'static str, T)], value: &T) -> &'static str {
+    table
+        .iter()
+        .find(|(_, candidate)| candidate == value)
+        .map_or("", |(code, _)| code)
+}
+
+// Value in the unit of a group, `None` when the quantity is of another dimension
+pub(crate) fn value_in(quantity: &Quantity, unit: Unit) -> Option<f64> {
+    if quantity.unit == unit {
+        return Some(quantity.value);
+    }
+    quantity
+        .convert_to(unit)
+        .map(|quantity| quantity.value)
+        .ok()
+}
+
+pub(crate) fn check_unit(
+    quantity: &Quantity,
+    expected: Dimension,
+    value: &'static str,
+) -> Result<(), EncodeReportError> {
+    if quantity.unit.dimension() == expected {
+        Ok(())
+    } else {
+        Err(EncodeReportError::InvalidUnit { value, expected })
+    }
+}
+
+// Rounds direction to tens of degrees, north is 360
+pub(crate) fn round_direction(degrees: f64) -> u32 {
+    match (degrees / 10.0).round() as u32 * 10 % 360 {
+        0 => 360,
+        degrees => degrees,
+    }
+}
+
+fn write_temperature(f: &mut fmt::Formatter<'_>, temperature: &Quantity) -> fmt::Result {
+    let Some(celsius) = value_in(temperature, Unit::Temperature(TemperatureScale::Celsius)) else {
+        return f.write_str("//");
+    };
+    let degrees = (celsius + 0.5).floor().abs() as u32;
+    // Sign of zero is kept, so a decoded `M00` is printed unchanged
+    if celsius.is_sign_negative() {
+        write!(f, "M{:02}", degrees)
+    } else {
+        write!(f, "{:02}", degrees)
+    }
+}
+
+// Sign digit followed by temperature in tenths of degree, slashes when not a temperature
+pub(crate) fn write_precise_temperature(
+    f: &mut fmt::Formatter<'_>,
+    temperature: &Quantity,
+) -> fmt::Result {
+    let Some(celsius) = value_in(temperature, Unit::Temperature(TemperatureScale::Celsius)) else {
+        return f.write_str("////");
+    };
+    let tenths = (celsius * 10.0).round() as i32;
+    let sign = if tenths < 0 { 1 } else { 0 };
+    write!(f, "{}{:03}", sign, tenths.unsigned_abs())
+}
+
+fn write_statute_miles(f: &mut fmt::Formatter<'_>, visibility: &Visibility) -> fmt::Result {
+    match visibility.qualifier {
+        Some(RangeQualifier::Below) => f.write_str("M")?,
+        Some(RangeQualifier::Above) => f.write_str("P")?,
+        None => {}
+    }
+    let sixteenths = (visibility.distance.value * 16.0).round() as u32;
+    let (whole, mut numerator, mut denominator) = (sixteenths / 16, sixteenths % 16, 16);
+    while numerator > 0 && numerator % 2 == 0 {
+        numerator /= 2;
+        denominator /= 2;
+    }
+    match (whole, numerator) {
+        (whole, 0) => write!(f, "{}SM", whole),
+        (0, numerator) => write!(f, "{}/{}SM", numerator, denominator),
+        (whole, numerator) => write!(f, "{} {}/{}SM", whole, numerator, denominator),
+    }
+}
+
+fn write_range(f: &mut fmt::Formatter<'_>, range: &Visibility, unit: Unit) -> fmt::Result {
+    match range.qualifier {
+        Some(RangeQualifier::Below) => f.write_str("M")?,
+        Some(RangeQualifier::Above) => f.write_str("P")?,
+        None => {}
+    }
+    match value_in(&range.distance, unit) {
+        Some(distance) => write!(f, "{:04}", distance.round() as u32),
+        None => f.write_str("////"),
+    }
+}
+
+// Remark groups decoded into `Remarks` fields
+enum DecodedRemark {
+    SeaLevelPressure,
+    Temperatures,
+}
+
+fn decoded_remark(group: &str) -> Option<DecodedRemark> {
+    let is_number =
+        |digits: &str, length: usize| digits.len() == length && parse_number(digits).is_some();
+    if group
+        .strip_prefix("SLP")
+        .is_some_and(|tenths| is_number(tenths, 3))
+    {
+        Some(DecodedRemark::SeaLevelPressure)
+    } else if group
+        .strip_prefix('T')
+        .is_some_and(|digits| is_number(digits, 8))
+    {
+        Some(DecodedRemark::Temperatures)
+    } else {
+        None
+    }
+}
+
+// Parses one group of the report body, recognised by its shape
+fn parse_group(metar: &mut Metar, group: &str) -> Result<(), ParseMetarError> {
+    let error = |make: fn(String) -> ParseMetarError| make(group.to_string());
+    if !group.is_ascii() {
+        return Err(error(ParseMetarError::UnexpectedGroup));
+    }
+    if is_wind(group) {
+        metar.wind = parse_wind(group).ok_or_else(|| error(ParseMetarError::InvalidWind))?;
+    } else if let Some(variation) = parse_wind_variation(group) {
+        let wind = metar
+            .wind
+            .as_mut()
+            .ok_or_else(|| error(ParseMetarError::InvalidWind))?;
+        wind.variation = Some(variation);
+    } else if group.ends_with("SM") {
+        metar.visibility = Some(
+            parse_statute_visibility(group)
+                .ok_or_else(|| error(ParseMetarError::InvalidVisibility))?,
+        );
+    } else if is_runway_state(group) || is_sea_surface(group) {
+        metar.supplementary.push(group.to_string());
+    } else if group.starts_with('R') && group[1..].starts_with(|c: char| c.is_ascii_digit()) {
+        let range = parse_runway_visual_range(group)
+            .ok_or_else(|| error(ParseMetarError::InvalidRunwayVisualRange))?;
+        metar.runway_visual_ranges.push(range);
+    } else if group.len() >= 4 && parse_number(&group[..4]).is_some() {
+        let visibility = parse_metric_visibility(group)
+            .ok_or_else(|| error(ParseMetarError::InvalidVisibility))?;
+        if metar.visibility.is_none() {
+            metar.visibility = Some(visibility);
+        } else {
+            metar.minimum_visibility = Some(visibility);
+        }
+    } else if is_cloud(group) {
+        let layer = parse_cloud(group).ok_or_else(|| error(ParseMetarError::InvalidCloud))?;
+        metar.clouds.push(layer);
+    } else if is_pressure(group) {
+        metar.altimeter =
+            parse_pressure(group).ok_or_else(|| error(ParseMetarError::InvalidPressure))?;
+    } else if group.contains('/') {
+        let (temperature, dew_point) =
+            parse_temperatures(group).ok_or_else(|| error(ParseMetarError::InvalidTemperature))?;
+        metar.temperature = temperature;
+        metar.dew_point = dew_point;
+    } else if let Some(recent) = group.strip_prefix("RE") {
+        let weather =
+            parse_weather(recent).ok_or_else(|| error(ParseMetarError::InvalidWeather))?;
+        metar.recent_weather.push(weather);
+    } else if let Some(weather) = parse_weather(group) {
+        metar.weather.push(weather);
+    } else if group.starts_with(['+', '-']) || group.starts_with("VC") {
+        return Err(error(ParseMetarError::InvalidWeather));
+    } else {
+        return Err(error(ParseMetarError::UnexpectedGroup));
+    }
+    Ok(())
+}
+
+// Runway state, such as `R25L/290050`, `R88/CLRD70`, `R/SNOCLO` or the former `25290050`
+fn is_runway_state(group: &str) -> bool {
+    if group.len() == 8 && parse_number(group).is_some() {
+        return true;
+    }
+    let Some((runway, state)) = group.split_once('/') else {
+        return group == "SNOCLO";
+    };
+    let valid_runway = runway == "R"
+        || runway.strip_prefix('R').is_some_and(|runway| {
+            matches!(runway.len(), 2 | 3)
+                && parse_number(&runway[..2]).is_some()
+                && matches!(&runway[2..], "" | "L" | "C" | "R")
+        });
+    let valid_state = state == "SNOCLO"
+        || state
+            .strip_prefix("CLRD")
+            .is_some_and(|friction| friction.len() == 2)
+        || (state.len() == 6
+            && state
+                .bytes()
+                .all(|byte| byte.is_ascii_digit() || byte == b'/'));
+    valid_runway && valid_state
+}
+
+// Sea surface temperature with state of the sea or wave height, such as `W15/S2` or `WM01/H14`
+fn is_sea_surface(group: &str) -> bool {
+    let Some((temperature, sea)) = group
+        .strip_prefix('W')
+        .and_then(|group| group.split_once('/'))
+    else {
+        return false;
+    };
+    let valid_sea = sea.len() >= 2
+        && sea.starts_with(['S', 'H'])
+        && sea[1..]
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || byte == b'/');
+    parse_temperature(temperature).is_some() && valid_sea
+}
+
+// Trend forecast starts with one of these groups and runs until the remarks
+fn is_trend(group: &str) -> bool {
+    matches!(group, "NOSIG" | "BECMG" | "TEMPO")
+}
+
+// Parses unsigned number consisting of digits only
+pub(crate) fn parse_number(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+pub(crate) fn parse_station(group: &str) -> Option<String> {
+    let valid = group.len() == 4
+        && group.starts_with(|c: char| c.is_ascii_uppercase())
+        && group
+            .bytes()
+            .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit());
+    valid.then(|| group.to_string())
+}
+
+pub(crate) fn parse_time(group: &str) -> Option<ReportTime> {
+    parse_day_time(group.strip_suffix('Z')?)
+}
+
+// Parses day, hour and minute, such as `251651`
+pub(crate) fn parse_day_time(digits: &str) -> Option<ReportTime> {
+    if digits.len() != 6 {
+        return None;
+    }
+    parse_number(digits)?;
+    let time = ReportTime {
+        day: digits[..2].parse().ok()?,
+        hour: digits[2..4].parse().ok()?,
+        minute: digits[4..].parse().ok()?,
+    };
+    let valid = (1..=31).contains(&time.day) && time.hour <= 23 && time.minute <= 59;
+    valid.then_some(time)
+}
+
+const WIND_UNITS: [(&str, SpeedUnit); 3] = [
+    ("KT", SpeedUnit::Knots),
+    ("MPS", SpeedUnit::MetersPerSecond),
+    ("KMH", SpeedUnit::KilometersPerHour),
+];
+
+pub(crate) fn is_wind(group: &str) -> bool {
+    WIND_UNITS.iter().any(|(suffix, _)| group.ends_with(suffix))
+        && (group.starts_with(|c: char| c.is_ascii_digit() || c == '/') || group.starts_with("VRB"))
+}
+
+// Parses wind group, missing wind (`/////KT`) is `None`
+pub(crate) fn parse_wind(group: &str) -> Option<Option<Wind>> {
+    let (body, unit) = WIND_UNITS
+        .iter()
+        .find_map(|(suffix, unit)| group.strip_suffix(suffix).map(|body| (body, *unit)))?;
+    if body.bytes().all(|byte| byte == b'/') {
+        return Some(None);
+    }
+    if body.len() < 5 {
+        return None;
+    }
+    let (direction, speeds) = body.split_at(3);
+    let (speed, gust) = match speeds.split_once('G') {
+        Some((speed, gust)) => (speed, Some(gust)),
+        None => (speeds, None),
+    };
+    // Speeds of 100 kt or 50 m/s and more may be reported as `P99KT` or `P49MPS`
+    let parse_speed = |speed: &str| {
+        let (above, speed) = match speed.strip_prefix('P') {
+            Some(speed) => (true, speed),
+            None => (false, speed),
+        };
+        let valid = (2..=3).contains(&speed.len());
+        valid
+            .then(|| parse_number(speed))
+            .flatten()
+            .map(|speed| (Quantity::new(speed as f64, Unit::Speed(unit)), above))
+    };
+    let (speed, speed_above) = parse_speed(speed)?;
+    let (gust, gust_above) = match gust {
+        Some(gust) => {
+            let (gust, above) = parse_speed(gust)?;
+            (Some(gust), above)
+        }
+        None => (None, false),
+    };
+    let direction = match direction {
+        "VRB" => WindDirection::Variable,
+        _ => match parse_number(direction)? {
+            0 if speed.value == 0.0 => WindDirection::Calm,
+            degrees if degrees <= 360 => WindDirection::Degrees(degrees as f64),
+            _ => return None,
+        },
+    };
+    Some(Some(Wind {
+        direction,
+        speed,
+        speed_above,
+        gust,
+        gust_above,
+        variation: None,
+    }))
+}
+
+// Parses extreme wind directions, such as `180V240`
+fn parse_wind_variation(group: &str) -> Option<(f64, f64)> {
+    let (from, to) = group.split_once('V')?;
+    if from.len() != 3 || to.len() != 3 {
+        return None;
+    }
+    let (from, to) = (parse_number(from)?, parse_number(to)?);
+    (from <= 360 && to <= 360).then_some((from as f64, to as f64))
+}
+
+// Parses visibility in statute miles, such as `10SM`, `M1/4SM` or `1 1/2SM`
+pub(crate) fn parse_statute_visibility(group: &str) -> Option<Visibility> {
+    let miles = group.strip_suffix("SM")?;
+    let (qualifier, miles) = parse_qualifier(miles);
+    let (whole, fraction) = match miles.split_once(' ') {
+        Some((whole, fraction)) => (parse_number(whole)?, Some(fraction)),
+        None if miles.contains('/') => (0, Some(miles)),
+        None => (parse_number(miles)?, None),
+    };
+    let fraction = match fraction {
+        Some(fraction) => {
+            let (numerator, denominator) = fraction.split_once('/')?;
+            let denominator = parse_number(denominator).filter(|denominator| *denominator > 0)?;
+            parse_number(numerator)? as f64 / denominator as f64
+        }
+        None => 0.0,
+    };
+    Some(Visibility {
+        distance: Quantity::new(
+            whole as f64 + fraction,
+            Unit::Length(LengthUnit::StatuteMile),
+        ),
+        qualifier,
+        direction: None,
+    })
+}
+
+// Parses visibility in meters with optional direction, such as `0800`, `9999`, `1500SW` or `4000NDV`
+pub(crate) fn parse_metric_visibility(group: &str) -> Option<Visibility> {
+    let (meters, direction) = group.split_at(4);
+    let meters = parse_number(meters)?;
+    let direction = match direction {
+        "" | "NDV" => None,
+        _ => Some(compass_to_degrees(direction, CompassLanguage::English).ok()?),
+    };
+    let (meters, qualifier) = match meters {
+        9999 => (10000.0, Some(RangeQualifier::Above)),
+        meters => (meters as f64, None),
+    };
+    Some(Visibility {
+        distance: Quantity::new(meters, Unit::Length(LengthUnit::Meter)),
+        qualifier,
+        direction,
+    })
+}
+
+// Splits M and P prefix of a distance
+fn parse_qualifier(distance: &str) -> (Option<RangeQualifier>, &str) {
+    if let Some(distance) = distance.strip_prefix('M') {
+        (Some(RangeQualifier::Below), distance)
+    } else if let Some(distance) = distance.strip_prefix('P') {
+        (Some(RangeQualifier::Above), distance)
+    } else {
+        (None, distance)
+    }
+}
+
+// Parses runway visual range, such as `R24L/1200FT`, `R06/P2000N` or `R10/0600V1000FT/U`
+fn parse_runway_visual_range(group: &str) -> Option<RunwayVisualRange> {
+    let (runway, range) = group.strip_prefix('R')?.split_once('/')?;
+    let valid_runway = matches!(runway.len(), 2 | 3)
+        && parse_number(&runway[..2]).is_some()
+        && matches!(&runway[2..], "" | "L" | "C" | "R");
+    if !valid_runway {
+        return None;
+    }
+    let tendency = match range.as_bytes().last()? {
+        b'U' => Some(RangeTendency::Up),
+        b'D' => Some(RangeTendency::Down),
+        b'N' => Some(RangeTendency::NoChange),
+        _ => None,
+    };
+    let range = match tendency {
+        Some(_) => range[..range.len() - 1].trim_end_matches('/'),
+        None => range,
+    };
+    let (range, unit) = match range.strip_suffix("FT") {
+        Some(range) => (range, LengthUnit::Foot),
+        None => (range, LengthUnit::Meter),
+    };
+    let parse_range = |range: &str| {
+        let (qualifier, distance) = parse_qualifier(range);
+        let distance = parse_number(distance).filter(|_| distance.len() == 4)?;
+        Some(Visibility {
+            distance: Quantity::new(distance as f64, Unit::Length(unit)),
+            qualifier,
+            direction: None,
+        })
+    };
+    let (range, maximum) = match range.split_once('V') {
+        Some((minimum, maximum)) => (parse_range(minimum)?, Some(parse_range(maximum)?)),
+        None => (parse_range(range)?, None),
+    };
+    Some(RunwayVisualRange {
+        runway: runway.to_string(),
+        range,
+        maximum,
+        tendency,
+    })
+}
+
+// Parses weather group, such as `-SHRA`, `+TSRAGR`, `VCFG` or `FZFG`
+pub(crate) fn parse_weather(group: &str) -> Option<Weather> {
+    let (intensity, group) = if let Some(group) = group.strip_prefix('-') {
+        (WeatherIntensity::Light, group)
+    } else if let Some(group) = group.strip_prefix('+') {
+        (WeatherIntensity::Heavy, group)
+    } else {
+        (WeatherIntensity::Moderate, group)
+    };
+    let (in_vicinity, group) = match group.strip_prefix("VC") {
+        Some(group) => (true, group),
+        None => (false, group),
+    };
+    if group.is_empty() || group.len() % 2 != 0 || !group.is_ascii() {
+        return None;
+    }
+    let descriptor = WEATHER_DESCRIPTORS
+        .iter()
+        .find(|(code, _)| group.starts_with(code))
+        .map(|(_, descriptor)| *descriptor);
+    let group = if descriptor.is_some() {
+        &group[2..]
+    } else {
+        group
+    };
+    let phenomena = (0..group.len())
+        .step_by(2)
+        .map(|index| {
+            WEATHER_PHENOMENA
+                .iter()
+                .find(|(code, _)| *code == &group[index..index + 2])
+                .map(|(_, phenomenon)| *phenomenon)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    // Only thunderstorm and showers may stand alone, as in `VCTS` or `VCSH`
+    let valid = !phenomena.is_empty()
+        || matches!(
+            descriptor,
+            Some(WeatherDescriptor::Thunderstorm | WeatherDescriptor::Showers)
+        );
+    valid.then_some(Weather {
+        intensity,
+        in_vicinity,
+        descriptor,
+        phenomena,
+    })
+}
+
+pub(crate) fn is_cloud(group: &str) -> bool {
+    // Groups of a layer with amount not observed have at least the base or `///` following
+    CLOUD_COVERS.iter().any(|(code, cover)| {
+        group.starts_with(code) && (*cover != CloudCover::NotObserved || group.len() >= 6)
+    })
+}
+
+// Parses cloud group, such as `SCT030`, `BKN012CB`, `VV002`, `//////CB` or `NSC`
+pub(crate) fn parse_cloud(group: &str) -> Option<CloudLayer> {
+    let (code, cover) = CLOUD_COVERS
+        .iter()
+        .find(|(code, _)| group.starts_with(code))?;
+    let rest = &group[code.len()..];
+    if !cover.has_base() {
+        return rest.is_empty().then_some(CloudLayer {
+            cover: *cover,
+            base: None,
+            cloud_type: None,
+        });
+    }
+    if rest.len() < 3 {
+        return None;
+    }
+    let (base, cloud_type) = rest.split_at(3);
+    let base = match base {
+        "///" => None,
+        _ => Some(Quantity::new(
+            parse_number(base)? as f64 * 100.0,
+            Unit::Length(LengthUnit::Foot),
+        )),
+    };
+    let cloud_type = match cloud_type {
+        "" | "///" => None,
+        "CB" => Some(CloudType::Cumulonimbus),
+        "TCU" => Some(CloudType::ToweringCumulus),
+        _ => return None,
+    };
+    if *cover == CloudCover::VerticalVisibility && cloud_type.is_some() {
+        return None;
+    }
+    Some(CloudLayer {
+        cover: *cover,
+        base,
+        cloud_type,
+    })
+}
+
+fn is_pressure(group: &str) -> bool {
+    group.starts_with(['A', 'Q'])
+        && group[1..].starts_with(|c: char| c.is_ascii_digit() || c == '/')
+}
+
+// Parses altimeter setting, such as `Q1013` or `A2992`, missing value (`Q////`) is `None`
+fn parse_pressure(group: &str) -> Option<Option<Quantity>> {
+    let (unit, value) = group.split_at(1);
+    if value.len() != 4 {
+        return None;
+    }
+    if value == "////" {
+        return Some(None);
+    }
+    let value = parse_number(value)? as f64;
+    let pressure = match unit {
+        "Q" => Quantity::new(value, Unit::Pressure(PressureUnit::Hectopascal)),
+        _ => Quantity::new(value / 100.0, Unit::Pressure(PressureUnit::InchOfMercury)),
+    };
+    Some(Some(pressure))
+}
+
+// Parses temperature and dew point, such as `18/09`, `M02/M11` or `05/`
+fn parse_temperatures(group: &str) -> Option<(Option<Quantity>, Option<Quantity>)> {
+    let (temperature, dew_point) = group.split_once('/')?;
+    Some((
+        parse_temperature(temperature)?,
+        parse_temperature(dew_point)?,
+    ))
+}
+
+// Parses whole degrees Celsius with M prefix for negative values, missing value (`//`) is `None`
+pub(crate) fn parse_temperature(temperature: &str) -> Option<Option<Quantity>> {
+    if matches!(temperature, "" | "//") {
+        return Some(None);
+    }
+    let (sign, digits) = match temperature.strip_prefix('M') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, temperature),
+    };
+    let degrees = parse_number(digits).filter(|_| digits.len() == 2)?;
+    Some(Some(celsius(sign * degrees as f64)))
+}
+
+pub(crate) fn celsius(degrees: f64) -> Quantity {
+    Quantity::new(degrees, Unit::Temperature(TemperatureScale::Celsius))
+}
+
+// Decodes sea level pressure and T group, other remarks are kept as text only
+fn parse_remarks(remarks: &[&str]) -> Result<Remarks, ParseMetarError> {
+    let mut decoded = Remarks {
+        text: remarks.join(" "),
+        ..Default::default()
+    };
+    for group in remarks {
+        let error = || ParseMetarError::InvalidRemark(group.to_string());
+        if let Some(pressure) = group.strip_prefix("SLP") {
+            // Sea level pressure not available or missing in automated reports
+            if matches!(pressure, "NO" | "///") {
+                continue;
+            }
+            let tenths = parse_number(pressure)
+                .filter(|_| pressure.len() == 3)
+                .ok_or_else(error)?;
+            // Only the tens, units and tenths of hPa are reported
+            let offset = if tenths < 500 { 1000.0 } else { 900.0 };
+            decoded.sea_level_pressure = Some(Quantity::new(
+                offset + tenths as f64 / 10.0,
+                Unit::Pressure(PressureUnit::Hectopascal),
+            ));
+        } else if let Some(digits) = group.strip_prefix('T') {
+            if parse_number(digits).is_none() {
+                continue;
+            }
+            if digits.len() != 8 {
+                return Err(error());
+            }
+            let (temperature, dew_point) = digits.split_at(4);
+            decoded.temperature = Some(parse_precise_temperature(temperature).ok_or_else(error)?);
+            decoded.dew_point = Some(parse_precise_temperature(dew_point).ok_or_else(error)?);
+        }
+    }
+    Ok(decoded)
+}
+
+// Parses sign digit followed by temperature in tenths of degree, such as `1022` for -2.2 °C
+fn parse_precise_temperature(digits: &str) -> Option<Quantity> {
+    let sign = match &digits[..1] {
+        "0" => 1.0,
+        "1" => -1.0,
+        _ => return None,
+    };
+    let tenths = parse_number(&digits[1..])?;
+    Some(celsius(sign * tenths as f64 / 10.0))
+}
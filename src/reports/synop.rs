@@ -0,0 +1,1245 @@
+use core::fmt;
+use core::iter::Peekable;
+use core::str::FromStr;
+
+use crate::reports::metar::{
+    celsius, check_unit, parse_number, round_direction, value_in, write_precise_temperature,
+};
+use crate::reports::{EncodeReportError, RangeQualifier, ReportTime, Visibility, Wind};
+use crate::{
+    Dimension, LengthUnit, Observation, PressureUnit, Quantity, Resolver, SpeedUnit,
+    TemperatureScale, Unit, Variable, WindDirection,
+};
+
+/// Synoptic report of a land station.
+///
+/// Parsed with [`FromStr`] from the WMO FM 12 (SYNOP) code, sections 0 to 3. Section 2 is decoded for the sea
+/// surface temperature only, sections 4 and 5 are ignored. Wind speed is a [`Quantity`] in m/s or knots,
+/// as declared by the wind indicator of section 0. Code figures of weather, cloud genera and pressure
+/// tendency characteristic are kept as WMO code table values, class codes of visibility and cloud height
+/// are decoded to the lower bound of the class. Groups missing in the report are `None`.
+///
+/// Reports are printed with [`fmt::Display`] in the same code, converting wind speed to the unit of the wind
+/// indicator, so a report can be built from an [`Observation`] with [`Synop::from_observation`] and sent as text.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::reports::Synop;
+/// use meteo_tools::{SpeedUnit, Unit, WindDirection};
+///
+/// let report = "AAXX 12064 11518 11465 72408 10182 20094 39923 40163 52012 60001 78162 83530 333 10205 20093";
+///
+/// let synop: Synop = report.parse().unwrap();
+/// let wind = synop.wind.unwrap();
+///
+/// assert_eq!(synop.station, "11518");
+/// assert_eq!(wind.direction, WindDirection::Degrees(240.0));
+/// assert_eq!(wind.speed.unit, Unit::Speed(SpeedUnit::Knots));
+/// assert_eq!(synop.temperature.unwrap().value, 18.2);
+/// assert_eq!(synop.sea_level_pressure.unwrap().value, 1016.3);
+/// assert_eq!(synop.present_weather, Some(81));
+/// assert_eq!(synop.max_temperature.unwrap().value, 20.5);
+/// assert_eq!(synop.to_string(), report);
+///
+/// // Trace of precipitation, no significant weather at a manned station
+/// let report = "AAXX 12064 11518 12465 72408 10182 20094 69901 333 79999";
+///
+/// let synop: Synop = report.parse().unwrap();
+///
+/// assert!(synop.precipitation.unwrap().trace);
+/// assert!(synop.daily_precipitation.unwrap().trace);
+/// assert_eq!(synop.station_type, Some(2));
+/// assert_eq!(synop.to_string(), report);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Synop {
+    /// WMO block and station number (IIiii).
+    pub station: String,
+    /// Day and nominal hour of the observation in UTC.
+    pub time: ReportTime,
+    /// Exact time of the observation, when it differs from the nominal hour (9GGgg group).
+    pub exact_time: Option<ReportTime>,
+    /// Unit of wind speed and whether the wind is measured (i<sub>w</sub>).
+    pub wind_indicator: Option<WindIndicator>,
+    /// Missing report (NIL).
+    pub nil: bool,
+    /// Whether precipitation groups are included, code table 1819 (i<sub>R</sub>), 3 when no precipitation fell
+    /// and 4 when it was not observed. `None` derives it from the precipitation groups.
+    pub precipitation_indicator: Option<u8>,
+    /// Type of station and whether the weather group is included, code table 1860 (i<sub>x</sub>),
+    /// 1 to 3 for manned and 4 to 7 for automatic stations. `None` derives it from the weather groups
+    /// as a manned station.
+    pub station_type: Option<u8>,
+    /// Height of the base of the lowest cloud in meters, lower bound of the class (h).
+    pub cloud_base: Option<Quantity>,
+    /// Horizontal visibility in meters, lower bound of the class (VV).
+    pub visibility: Option<Visibility>,
+    /// Total cloud cover in oktas, 9 when the sky is obscured (N).
+    pub total_cloud_cover: Option<u8>,
+    /// Surface wind, gust from the 911 group of section 3.
+    pub wind: Option<Wind>,
+    /// Air temperature in degrees Celsius.
+    pub temperature: Option<Quantity>,
+    /// Dew point in degrees Celsius.
+    pub dew_point: Option<Quantity>,
+    /// Relative humidity in percent, reported instead of the dew point (29UUU group).
+    pub relative_humidity: Option<f64>,
+    /// Station pressure in hPa.
+    pub station_pressure: Option<Quantity>,
+    /// Pressure reduced to mean sea level in hPa.
+    pub sea_level_pressure: Option<Quantity>,
+    /// Pressure tendency over the last three hours.
+    pub pressure_tendency: Option<PressureTendency>,
+    /// Precipitation of section 1.
+    pub precipitation: Option<Precipitation>,
+    /// Present weather, code table 4677 or 4680 for automatic stations (ww).
+    pub present_weather: Option<u8>,
+    /// Past weather, code table 4561 or 4531 for automatic stations (W<sub>1</sub>W<sub>2</sub>).
+    pub past_weather: [Option<u8>; 2],
+    /// Low, middle and high clouds (8 group).
+    pub clouds: Option<SynopClouds>,
+    /// Sea surface temperature in degrees Celsius (section 2).
+    pub sea_surface_temperature: Option<Quantity>,
+    /// Maximum air temperature in degrees Celsius (section 3).
+    pub max_temperature: Option<Quantity>,
+    /// Minimum air temperature in degrees Celsius (section 3).
+    pub min_temperature: Option<Quantity>,
+    /// Total snow depth in centimeters, 0 for less than 0.5 cm (section 3).
+    pub snow_depth: Option<Quantity>,
+    /// Sunshine duration of the previous day in hours (section 3).
+    pub sunshine_duration: Option<f64>,
+    /// Precipitation of section 3.
+    pub supplementary_precipitation: Option<Precipitation>,
+    /// Precipitation over the last 24 hours (7RRRR group of section 3).
+    pub daily_precipitation: Option<Precipitation>,
+    /// Individual cloud layers (section 3).
+    pub cloud_layers: Vec<SynopCloudLayer>,
+}
+
+/// Wind speed unit and how the speed was obtained, code table 1855.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindIndicator {
+    /// Estimated speed in m/s (0).
+    EstimatedMetersPerSecond,
+    /// Speed measured by an anemometer in m/s (1).
+    MeasuredMetersPerSecond,
+    /// Estimated speed in knots (3).
+    EstimatedKnots,
+    /// Speed measured by an anemometer in knots (4).
+    MeasuredKnots,
+}
+
+/// Characteristic and amount of the pressure tendency over the last three hours (5appp group).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressureTendency {
+    /// Characteristic, code table 0200, 0 to 3 rising, 4 steady and 5 to 8 falling.
+    pub characteristic: u8,
+    /// Pressure change in hPa, negative when falling.
+    pub change: Quantity,
+}
+
+/// Amount of precipitation over a period (6RRRt<sub>R</sub> group).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Precipitation {
+    /// Amount in mm, 0 for a trace.
+    pub amount: Quantity,
+    /// Less than 0.05 mm fell, coded as 990 in 6RRRt<sub>R</sub> and 9999 in 7RRRR groups.
+    pub trace: bool,
+    /// Period ending at the time of observation in hours, when given.
+    pub period: Option<u8>,
+}
+
+/// Amount and genera of clouds (8N<sub>h</sub>C<sub>L</sub>C<sub>M</sub>C<sub>H</sub> group).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SynopClouds {
+    /// Amount of low clouds, or middle clouds when there are no low clouds, in oktas.
+    pub amount: Option<u8>,
+    /// Low clouds, code table 0513.
+    pub low: Option<u8>,
+    /// Middle clouds, code table 0515.
+    pub middle: Option<u8>,
+    /// High clouds, code table 0509.
+    pub high: Option<u8>,
+}
+
+/// Cloud layer of section 3 (8N<sub>s</sub>Ch<sub>s</sub>h<sub>s</sub> group).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SynopCloudLayer {
+    /// Amount in oktas, 9 when the sky is obscured.
+    pub amount: Option<u8>,
+    /// Cloud genus, code table 0500.
+    pub genus: Option<u8>,
+    /// Height of the base in meters, lower bound of the class.
+    pub base: Option<Quantity>,
+}
+
+/// Error returned when a SYNOP report cannot be parsed. Carries the offending group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSynopError {
+    /// Report is empty.
+    Empty,
+    /// Report ends before the station, indicators or wind.
+    Incomplete,
+    /// Report does not start with `AAXX`, only reports of land stations are supported.
+    UnsupportedReport(String),
+    /// Time and wind indicator group is not `YYGGi` or is out of range.
+    InvalidTime(String),
+    /// Station is not a five digit WMO station number.
+    InvalidStation(String),
+    /// Precipitation, station type, cloud base and visibility group is malformed.
+    InvalidIndicators(String),
+    /// Cloud cover and wind group is malformed or wind indicator is missing.
+    InvalidWind(String),
+    /// Temperature, dew point or humidity group is malformed.
+    InvalidTemperature(String),
+    /// Pressure or pressure tendency group is malformed.
+    InvalidPressure(String),
+    /// Precipitation group is malformed.
+    InvalidPrecipitation(String),
+    /// Weather or cloud group is malformed.
+    InvalidCloud(String),
+    /// Group is not a five character code group or is out of order.
+    UnexpectedGroup(String),
+}
+
+impl fmt::Display for ParseSynopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, group) = match self {
+            ParseSynopError::Empty => return f.write_str("empty report"),
+            ParseSynopError::Incomplete => {
+                return f.write_str("report has no station, indicators or wind")
+            }
+            ParseSynopError::UnsupportedReport(group) => {
+                return write!(f, "unsupported report `{}`", group)
+            }
+            ParseSynopError::InvalidTime(group) => ("time", group),
+            ParseSynopError::InvalidStation(group) => ("station", group),
+            ParseSynopError::InvalidIndicators(group) => ("indicators", group),
+            ParseSynopError::InvalidWind(group) => ("wind", group),
+            ParseSynopError::InvalidTemperature(group) => ("temperature", group),
+            ParseSynopError::InvalidPressure(group) => ("pressure", group),
+            ParseSynopError::InvalidPrecipitation(group) => ("precipitation", group),
+            ParseSynopError::InvalidCloud(group) => ("cloud", group),
+            ParseSynopError::UnexpectedGroup(group) => {
+                return write!(f, "unexpected group `{}`", group)
+            }
+        };
+        write!(f, "invalid {} group `{}`", kind, group)
+    }
+}
+
+impl std::error::Error for ParseSynopError {}
+
+impl WindIndicator {
+    /// Unit of the reported wind speed.
+    pub fn unit(&self) -> SpeedUnit {
+        match self {
+            WindIndicator::EstimatedMetersPerSecond | WindIndicator::MeasuredMetersPerSecond => {
+                SpeedUnit::MetersPerSecond
+            }
+            WindIndicator::EstimatedKnots | WindIndicator::MeasuredKnots => SpeedUnit::Knots,
+        }
+    }
+
+    fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(WindIndicator::EstimatedMetersPerSecond),
+            1 => Some(WindIndicator::MeasuredMetersPerSecond),
+            3 => Some(WindIndicator::EstimatedKnots),
+            4 => Some(WindIndicator::MeasuredKnots),
+            _ => None,
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            WindIndicator::EstimatedMetersPerSecond => 0,
+            WindIndicator::MeasuredMetersPerSecond => 1,
+            WindIndicator::EstimatedKnots => 3,
+            WindIndicator::MeasuredKnots => 4,
+        }
+    }
+}
+
+// Lower bounds of cloud base height classes in meters, code table 1600
+const CLOUD_BASE_CLASSES: [f64; 10] = [
+    0.0, 50.0, 100.0, 200.0, 300.0, 600.0, 1000.0, 1500.0, 2000.0, 2500.0,
+];
+
+// Period of precipitation in hours, code table 4019
+const PRECIPITATION_PERIODS: [(u32, u8); 9] = [
+    (1, 6),
+    (2, 12),
+    (3, 18),
+    (4, 24),
+    (5, 1),
+    (6, 2),
+    (7, 3),
+    (8, 9),
+    (9, 15),
+];
+
+impl Synop {
+    /// Builds a report of an automatic station of given WMO station number and time from an [`Observation`].
+    ///
+    /// Wind is reported as measured in m/s, set [`Synop::wind_indicator`] to report it in knots.
+    /// Dew point is derived from the relative humidity and the observation pressure is reported as the station
+    /// pressure. Sea level pressure is not derived, set [`Synop::sea_level_pressure`] when it is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{ReportTime, Synop, WindIndicator};
+    /// use meteo_tools::{Observation, WindDirection};
+    ///
+    /// let observation = Observation::new(18.2, 50.0)
+    ///     .with_pressure(992.3)
+    ///     .with_wind(4.1, WindDirection::Degrees(243.0))
+    ///     .with_visibility(16000.0);
+    /// let time = ReportTime { day: 12, hour: 6, minute: 0 };
+    ///
+    /// let mut synop = Synop::from_observation("11518", time, &observation);
+    ///
+    /// assert_eq!(synop.to_string(), "AAXX 12061 11518 46/66 /2404 10182 20076 39923");
+    ///
+    /// synop.wind_indicator = Some(WindIndicator::MeasuredKnots);
+    ///
+    /// assert_eq!(synop.to_string(), "AAXX 12064 11518 46/66 /2408 10182 20076 39923");
+    ///
+    /// // Wind rounding to zero speed is reported as calm
+    /// let observation = Observation::new(18.2, 50.0).with_wind(0.3, WindDirection::Degrees(243.0));
+    /// let calm = Synop::from_observation("11518", time, &observation);
+    ///
+    /// assert_eq!(calm.to_string(), "AAXX 12061 11518 46/// /0000 10182 20076");
+    /// ```
+    pub fn from_observation(station: &str, time: ReportTime, observation: &Observation) -> Self {
+        let wind = observation.wind_speed.map(|speed| Wind {
+            direction: observation
+                .wind_direction
+                .unwrap_or(WindDirection::Variable),
+            speed: Quantity::new(speed, Unit::Speed(SpeedUnit::MetersPerSecond)),
+            speed_above: false,
+            gust: None,
+            gust_above: false,
+            variation: None,
+        });
+        let visibility = observation.visibility.map(|visibility| Visibility {
+            distance: Quantity::new(visibility, Unit::Length(LengthUnit::Meter)),
+            qualifier: None,
+            direction: None,
+        });
+        Synop {
+            station: station.to_string(),
+            time,
+            wind_indicator: Some(WindIndicator::MeasuredMetersPerSecond),
+            precipitation_indicator: Some(4),
+            station_type: Some(6),
+            visibility,
+            wind,
+            temperature: Some(celsius(observation.temperature)),
+            dew_point: Some(celsius(observation.derived().dew_point)),
+            station_pressure: observation.pressure.map(hectopascals),
+            ..Default::default()
+        }
+    }
+
+    /// Encodes the report like [`fmt::Display`], but fails when a value cannot be converted to the unit of its group
+    /// instead of printing the group as missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{EncodeReportError, Synop};
+    /// use meteo_tools::{Dimension, LengthUnit, Quantity, Unit};
+    ///
+    /// let report = "AAXX 12064 11518 11465 72408 10182 20094 39923 40163 60001 78162";
+    /// let mut synop: Synop = report.parse().unwrap();
+    /// synop.station_pressure = Some(Quantity::new(992.3, Unit::Length(LengthUnit::Meter)));
+    ///
+    /// let error = EncodeReportError::InvalidUnit { value: "station pressure", expected: Dimension::Pressure };
+    ///
+    /// assert_eq!(synop.encode(), Err(error));
+    /// assert_eq!(synop.to_string(), "AAXX 12064 11518 11465 72408 10182 20094 3//// 40163 60001 78162");
+    /// ```
+    pub fn encode(&self) -> Result<String, EncodeReportError> {
+        if let Some(wind) = &self.wind {
+            check_unit(&wind.speed, Dimension::Speed, "wind speed")?;
+            if let Some(gust) = &wind.gust {
+                check_unit(gust, Dimension::Speed, "gust speed")?;
+            }
+        }
+        if let Some(visibility) = &self.visibility {
+            check_unit(&visibility.distance, Dimension::Length, "visibility")?;
+        }
+        let layers = self
+            .cloud_layers
+            .iter()
+            .filter_map(|layer| layer.base.as_ref());
+        for base in self.cloud_base.iter().chain(layers) {
+            check_unit(base, Dimension::Length, "cloud base")?;
+        }
+        let temperatures = [
+            (&self.temperature, "temperature"),
+            (&self.dew_point, "dew point"),
+            (&self.sea_surface_temperature, "sea surface temperature"),
+            (&self.max_temperature, "maximum temperature"),
+            (&self.min_temperature, "minimum temperature"),
+        ];
+        for (temperature, value) in temperatures {
+            if let Some(temperature) = temperature {
+                check_unit(temperature, Dimension::Temperature, value)?;
+            }
+        }
+        let pressures = [
+            (self.station_pressure.as_ref(), "station pressure"),
+            (self.sea_level_pressure.as_ref(), "sea level pressure"),
+            (
+                self.pressure_tendency
+                    .as_ref()
+                    .map(|tendency| &tendency.change),
+                "pressure tendency",
+            ),
+        ];
+        for (pressure, value) in pressures {
+            if let Some(pressure) = pressure {
+                check_unit(pressure, Dimension::Pressure, value)?;
+            }
+        }
+        let precipitation = self
+            .precipitation
+            .iter()
+            .chain(&self.supplementary_precipitation)
+            .chain(&self.daily_precipitation);
+        for precipitation in precipitation {
+            check_unit(&precipitation.amount, Dimension::Length, "precipitation")?;
+        }
+        if let Some(depth) = &self.snow_depth {
+            check_unit(depth, Dimension::Length, "snow depth")?;
+        }
+        Ok(self.to_string())
+    }
+
+    /// Converts the report to an [`Observation`], when temperature and dew point or relative humidity are reported.
+    ///
+    /// Relative humidity is derived from the dew point when not reported, wind speed is converted to m/s
+    /// and the station pressure is taken as the observation pressure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::Synop;
+    /// use meteo_tools::WindDirection;
+    ///
+    /// let synop: Synop = "AAXX 12064 11518 11465 72408 10182 29056 39923 40163".parse().unwrap();
+    ///
+    /// let observation = synop.observation().unwrap();
+    ///
+    /// assert_eq!(observation.temperature, 18.2);
+    /// assert_eq!(observation.relative_humidity, 56.0);
+    /// assert_eq!(observation.pressure, Some(992.3));
+    /// assert_eq!(observation.wind_speed, Some(4.1156));
+    /// assert_eq!(observation.wind_direction, Some(WindDirection::Degrees(240.0)));
+    /// assert_eq!(observation.visibility, Some(15000.0));
+    /// ```
+    pub fn observation(&self) -> Option<Observation> {
+        let temperature = self.temperature?.convert_to(celsius_unit()).ok()?.value;
+        let relative_humidity = match (self.relative_humidity, self.dew_point) {
+            (Some(relative_humidity), _) => relative_humidity,
+            (None, Some(dew_point)) => Resolver::new()
+                .with(Variable::Temperature, temperature)
+                .with(
+                    Variable::DewPoint,
+                    dew_point.convert_to(celsius_unit()).ok()?.value,
+                )
+                .resolve(Variable::RelativeHumidity)
+                .ok()?,
+            (None, None) => return None,
+        };
+        let mut observation = Observation::new(temperature, relative_humidity);
+        if let Some(pressure) = self.station_pressure {
+            let pressure = pressure
+                .convert_to(Unit::Pressure(PressureUnit::Hectopascal))
+                .ok()?;
+            observation = observation.with_pressure(pressure.value);
+        }
+        if let Some(wind) = self.wind {
+            let speed = wind
+                .speed
+                .convert_to(Unit::Speed(SpeedUnit::MetersPerSecond))
+                .ok()?;
+            observation = observation.with_wind(speed.value, wind.direction);
+        }
+        if let Some(visibility) = self.visibility {
+            let distance = visibility
+                .distance
+                .convert_to(Unit::Length(LengthUnit::Meter))
+                .ok()?;
+            observation = observation.with_visibility(distance.value);
+        }
+        Some(observation)
+    }
+}
+
+impl FromStr for Synop {
+    type Err = ParseSynopError;
+
+    /// Parses a report starting with `AAXX` and optionally ending with `=`.
+    ///
+    /// Groups of section 1 must follow in the order of their indicators, unknown groups of sections 2
+    /// and 3 are skipped.
+    fn from_str(report: &str) -> Result<Self, Self::Err> {
+        let report = report.trim().trim_end_matches('=');
+        let mut groups = report.split_whitespace().peekable();
+        let header = groups.next().ok_or(ParseSynopError::Empty)?;
+        if header != "AAXX" {
+            return Err(ParseSynopError::UnsupportedReport(header.to_string()));
+        }
+        let time = groups.next().ok_or(ParseSynopError::Incomplete)?;
+        let (time, wind_indicator) =
+            parse_synop_time(time).ok_or_else(|| ParseSynopError::InvalidTime(time.to_string()))?;
+        let station = groups.next().ok_or(ParseSynopError::Incomplete)?;
+        if station.len() != 5 || parse_number(station).is_none() {
+            return Err(ParseSynopError::InvalidStation(station.to_string()));
+        }
+        let mut synop = Synop {
+            station: station.to_string(),
+            time,
+            wind_indicator,
+            ..Default::default()
+        };
+        if groups.next_if_eq(&"NIL").is_some() {
+            synop.nil = true;
+            return Ok(synop);
+        }
+
+        let indicators = groups.next().ok_or(ParseSynopError::Incomplete)?;
+        parse_indicators(&mut synop, indicators)
+            .ok_or_else(|| ParseSynopError::InvalidIndicators(indicators.to_string()))?;
+        let wind = groups.next().ok_or(ParseSynopError::Incomplete)?;
+        let unit = synop.wind_indicator.map(|indicator| indicator.unit());
+        let (total_cloud_cover, direction) = parse_cloud_cover_and_direction(wind)
+            .ok_or_else(|| ParseSynopError::InvalidWind(wind.to_string()))?;
+        synop.total_cloud_cover = total_cloud_cover;
+        let speed = parse_speed(wind, &mut groups, unit)
+            .ok_or_else(|| ParseSynopError::InvalidWind(wind.to_string()))?;
+        synop.wind = match (direction, speed) {
+            (Some(WindDirection::Calm), Some(speed)) if speed.value != 0.0 => {
+                return Err(ParseSynopError::InvalidWind(wind.to_string()))
+            }
+            (Some(direction), Some(speed)) => Some(Wind {
+                direction,
+                speed,
+                speed_above: false,
+                gust: None,
+                gust_above: false,
+                variation: None,
+            }),
+            _ => None,
+        };
+
+        let mut section = 1;
+        let mut last_indicator = 0;
+        let mut radiation = false;
+        while let Some(group) = groups.next() {
+            match group {
+                "333" => {
+                    section = 3;
+                    continue;
+                }
+                "444" | "555" => break,
+                _ if group.len() == 5 && group.starts_with("222") && section == 1 => {
+                    section = 2;
+                    continue;
+                }
+                _ => {}
+            }
+            let indicator = parse_number(group.get(..1).unwrap_or_default())
+                .filter(|_| is_code_group(group))
+                .ok_or_else(|| ParseSynopError::UnexpectedGroup(group.to_string()))?;
+            match section {
+                1 => {
+                    if indicator <= last_indicator {
+                        return Err(ParseSynopError::UnexpectedGroup(group.to_string()));
+                    }
+                    last_indicator = indicator;
+                    parse_section_1(&mut synop, group, indicator)?;
+                }
+                2 => {
+                    if indicator == 0 {
+                        synop.sea_surface_temperature =
+                            parse_sea_temperature(group).ok_or_else(|| {
+                                ParseSynopError::InvalidTemperature(group.to_string())
+                            })?;
+                    }
+                }
+                _ => {
+                    // Radiation groups follow the sunshine group and reuse the indicators 0 to 4
+                    if radiation && indicator <= 4 {
+                        continue;
+                    }
+                    radiation = group.starts_with("55");
+                    parse_section_3(&mut synop, group, indicator, &mut groups)?;
+                }
+            }
+        }
+        Ok(synop)
+    }
+}
+
+impl fmt::Display for Synop {
+    /// Prints the report, groups with all values missing are left out.
+    ///
+    /// Wind speed is converted to the unit of the wind indicator and rounded, speeds of 99 units and more
+    /// use the 00fff group. Temperatures and pressures are rounded to tenths, visibility and cloud heights
+    /// are coded by the class they fall into. Without explicit indicators, precipitation in section 1 and 3 sets
+    /// the precipitation indicator, otherwise precipitation is reported as not observed, and the station type
+    /// is a manned station with or without weather. Values failing to convert to the unit of their group,
+    /// such as a pressure in meters, are printed as missing, use [`Synop::encode`] to get an error instead.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AAXX {:02}{:02}", self.time.day, self.time.hour)?;
+        write_code(f, self.wind_indicator.map(|indicator| indicator.code()), 1)?;
+        write!(f, " {}", self.station)?;
+        if self.nil {
+            return f.write_str(" NIL");
+        }
+
+        let precipitation_indicator = self.precipitation_indicator.unwrap_or(
+            match (
+                self.precipitation.is_some(),
+                self.supplementary_precipitation.is_some(),
+            ) {
+                (true, true) => 0,
+                (true, false) => 1,
+                (false, true) => 2,
+                (false, false) => 4,
+            },
+        );
+        let has_weather =
+            self.present_weather.is_some() || self.past_weather.iter().any(Option::is_some);
+        let station_type = self.station_type.unwrap_or(if has_weather { 1 } else { 3 });
+        write!(f, " {}{}", precipitation_indicator, station_type)?;
+        write_code(f, self.cloud_base.as_ref().and_then(cloud_base_code), 1)?;
+        write_code(f, self.visibility.as_ref().and_then(visibility_code), 2)?;
+
+        f.write_str(" ")?;
+        write_code(f, self.total_cloud_cover.map(u32::from), 1)?;
+        self.write_wind(f)?;
+
+        if let Some(temperature) = &self.temperature {
+            f.write_str(" 1")?;
+            write_precise_temperature(f, temperature)?;
+        }
+        if let Some(dew_point) = &self.dew_point {
+            f.write_str(" 2")?;
+            write_precise_temperature(f, dew_point)?;
+        } else if let Some(relative_humidity) = self.relative_humidity {
+            write!(f, " 29{:03}", relative_humidity.round() as u32)?;
+        }
+        if let Some(pressure) = &self.station_pressure {
+            f.write_str(" 3")?;
+            write_pressure(f, pressure)?;
+        }
+        if let Some(pressure) = &self.sea_level_pressure {
+            f.write_str(" 4")?;
+            write_pressure(f, pressure)?;
+        }
+        if let Some(tendency) = &self.pressure_tendency {
+            write!(f, " 5{}", tendency.characteristic)?;
+            let change = value_in(&tendency.change, Unit::Pressure(PressureUnit::Hectopascal));
+            let tenths = change.map(|change| (change.abs() * 10.0).round() as u32);
+            write_code(f, tenths, 3)?;
+        }
+        if let Some(precipitation) = &self.precipitation {
+            write_precipitation(f, precipitation)?;
+        }
+        if has_weather {
+            f.write_str(" 7")?;
+            write_code(f, self.present_weather.map(u32::from), 2)?;
+            for weather in self.past_weather {
+                write_code(f, weather.map(u32::from), 1)?;
+            }
+        }
+        if let Some(clouds) = &self.clouds {
+            f.write_str(" 8")?;
+            for code in [clouds.amount, clouds.low, clouds.middle, clouds.high] {
+                write_code(f, code.map(u32::from), 1)?;
+            }
+        }
+        if let Some(time) = &self.exact_time {
+            write!(f, " 9{:02}{:02}", time.hour, time.minute)?;
+        }
+
+        if let Some(temperature) = &self.sea_surface_temperature {
+            f.write_str(" 222// 0")?;
+            write_precise_temperature(f, temperature)?;
+        }
+
+        self.write_section_3(f)
+    }
+}
+
+impl Synop {
+    // Cloud cover is written before, direction and speed follow, a wind rounded to zero speed is calm
+    fn write_wind(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(wind) = &self.wind else {
+            return f.write_str("////");
+        };
+        let speed = self.speed_code(&wind.speed);
+        match wind.direction {
+            _ if speed == Some(0) => return f.write_str("0000"),
+            WindDirection::Calm => return f.write_str("0000"),
+            WindDirection::Variable => f.write_str("99")?,
+            WindDirection::Degrees(degrees) => write!(f, "{:02}", round_direction(degrees) / 10)?,
+        }
+        write_speed(f, speed)
+    }
+
+    fn write_section_3(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gust = self
+            .wind
+            .and_then(|wind| wind.gust)
+            .and_then(|gust| self.speed_code(&gust));
+        let has_section = self.max_temperature.is_some()
+            || self.min_temperature.is_some()
+            || self.snow_depth.is_some()
+            || self.sunshine_duration.is_some()
+            || self.supplementary_precipitation.is_some()
+            || self.daily_precipitation.is_some()
+            || !self.cloud_layers.is_empty()
+            || gust.is_some();
+        if !has_section {
+            return Ok(());
+        }
+        f.write_str(" 333")?;
+        if let Some(temperature) = &self.max_temperature {
+            f.write_str(" 1")?;
+            write_precise_temperature(f, temperature)?;
+        }
+        if let Some(temperature) = &self.min_temperature {
+            f.write_str(" 2")?;
+            write_precise_temperature(f, temperature)?;
+        }
+        if let Some(depth) = &self.snow_depth {
+            let centimeters = value_in(depth, Unit::Length(LengthUnit::Centimeter));
+            let code = centimeters.map(|centimeters| match centimeters.round() as u32 {
+                0 => 997,
+                centimeters => centimeters.min(996),
+            });
+            f.write_str(" 4/")?;
+            write_code(f, code, 3)?;
+        }
+        if let Some(duration) = self.sunshine_duration {
+            write!(f, " 55{:03}", (duration * 10.0).round() as u32)?;
+        }
+        if let Some(precipitation) = &self.supplementary_precipitation {
+            write_precipitation(f, precipitation)?;
+        }
+        if let Some(precipitation) = &self.daily_precipitation {
+            let millimeters = value_in(&precipitation.amount, Unit::Length(LengthUnit::Millimeter));
+            let code = if precipitation.trace {
+                Some(9999)
+            } else {
+                millimeters.map(|millimeters| ((millimeters * 10.0).round() as u32).min(9998))
+            };
+            f.write_str(" 7")?;
+            write_code(f, code, 4)?;
+        }
+        for layer in &self.cloud_layers {
+            f.write_str(" 8")?;
+            write_code(f, layer.amount.map(u32::from), 1)?;
+            write_code(f, layer.genus.map(u32::from), 1)?;
+            write_code(f, layer.base.as_ref().and_then(cloud_height_code), 2)?;
+        }
+        if let Some(gust) = gust {
+            f.write_str(" 911")?;
+            write_speed(f, Some(gust))?;
+        }
+        Ok(())
+    }
+
+    // Speed in whole units of the wind indicator, `None` when the indicator is missing
+    fn speed_code(&self, speed: &Quantity) -> Option<u32> {
+        let indicator = self.wind_indicator?;
+        let value = value_in(speed, Unit::Speed(indicator.unit()))?;
+        Some(value.round() as u32)
+    }
+}
+
+// Writes code figure of given width, slashes when missing
+fn write_code(f: &mut fmt::Formatter<'_>, code: Option<u32>, width: usize) -> fmt::Result {
+    match code {
+        Some(code) => write!(f, "{:0width$}", code, width = width),
+        None => write!(f, "{:/<width$}", "", width = width),
+    }
+}
+
+// Two digit speed, speeds of 99 units and more follow in the 00fff group
+fn write_speed(f: &mut fmt::Formatter<'_>, speed: Option<u32>) -> fmt::Result {
+    match speed {
+        Some(speed) if speed >= 99 => write!(f, "99 00{:03}", speed.min(999)),
+        Some(speed) => write!(f, "{:02}", speed),
+        None => f.write_str("//"),
+    }
+}
+
+// Last four digits of pressure in tenths of hPa
+fn write_pressure(f: &mut fmt::Formatter<'_>, pressure: &Quantity) -> fmt::Result {
+    let hectopascals = value_in(pressure, Unit::Pressure(PressureUnit::Hectopascal));
+    let tenths = hectopascals.map(|hectopascals| (hectopascals * 10.0).round() as u32 % 10000);
+    write_code(f, tenths, 4)
+}
+
+fn write_precipitation(f: &mut fmt::Formatter<'_>, precipitation: &Precipitation) -> fmt::Result {
+    let millimeters = value_in(&precipitation.amount, Unit::Length(LengthUnit::Millimeter));
+    let code = if precipitation.trace {
+        Some(990)
+    } else {
+        millimeters.map(|millimeters| match (millimeters * 10.0).round() as u32 {
+            0 => 0,
+            tenths @ 1..=9 => 990 + tenths,
+            _ => (millimeters.round() as u32).min(989),
+        })
+    };
+    f.write_str(" 6")?;
+    write_code(f, code, 3)?;
+    let period = precipitation.period.and_then(|hours| {
+        PRECIPITATION_PERIODS
+            .iter()
+            .find(|(_, period)| *period == hours)
+            .map(|(code, _)| *code)
+    });
+    write_code(f, period, 1)
+}
+
+fn cloud_base_code(base: &Quantity) -> Option<u32> {
+    let meters = value_in(base, Unit::Length(LengthUnit::Meter))?;
+    let class = CLOUD_BASE_CLASSES
+        .iter()
+        .rposition(|bound| meters + 1e-6 >= *bound)
+        .unwrap_or_default();
+    Some(class as u32)
+}
+
+// Visibility code of the class the distance falls into, code table 4377
+fn visibility_code(visibility: &Visibility) -> Option<u32> {
+    let meters = value_in(&visibility.distance, Unit::Length(LengthUnit::Meter))? + 1e-6;
+    let code = match visibility.qualifier {
+        Some(RangeQualifier::Below) if meters <= 100.0 => 0,
+        Some(RangeQualifier::Above) if meters >= 70000.0 => 89,
+        _ if meters < 6000.0 => ((meters / 100.0).floor() as u32).min(50),
+        _ if meters < 35000.0 => ((meters / 1000.0).floor() as u32 + 50).min(80),
+        _ if meters <= 70000.0 => (meters / 5000.0).floor() as u32 + 74,
+        _ => 89,
+    };
+    Some(code)
+}
+
+// Cloud height code of the class the height falls into, code table 1677
+fn cloud_height_code(base: &Quantity) -> Option<u32> {
+    let meters = value_in(base, Unit::Length(LengthUnit::Meter))? + 1e-6;
+    let code = match meters {
+        _ if meters < 1800.0 => ((meters / 30.0).floor() as u32).min(50),
+        _ if meters < 10500.0 => ((meters / 300.0).floor() as u32 + 50).min(80),
+        _ if meters <= 21000.0 => ((meters - 9000.0) / 1500.0).floor() as u32 + 80,
+        _ => 89,
+    };
+    Some(code)
+}
+
+fn celsius_unit() -> Unit {
+    Unit::Temperature(TemperatureScale::Celsius)
+}
+
+fn hectopascals(pressure: f64) -> Quantity {
+    Quantity::new(pressure, Unit::Pressure(PressureUnit::Hectopascal))
+}
+
+// Five digits or slashes for missing values
+fn is_code_group(group: &str) -> bool {
+    group.len() == 5
+        && group
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || byte == b'/')
+}
+
+// Code figure at given positions, `None` when reported missing
+fn code_at(group: &str, start: usize, end: usize) -> Option<u32> {
+    parse_number(&group[start..end])
+}
+
+// Parses day, nominal hour and wind indicator, such as `12064`
+fn parse_synop_time(group: &str) -> Option<(ReportTime, Option<WindIndicator>)> {
+    if !is_code_group(group) {
+        return None;
+    }
+    let time = ReportTime {
+        day: code_at(group, 0, 2)? as u8,
+        hour: code_at(group, 2, 4)? as u8,
+        minute: 0,
+    };
+    let wind_indicator = match &group[4..] {
+        "/" => None,
+        code => Some(WindIndicator::from_code(parse_number(code)?)?),
+    };
+    let valid = (1..=31).contains(&time.day) && time.hour <= 23;
+    valid.then_some((time, wind_indicator))
+}
+
+// Parses i_R i_x h VV group
+fn parse_indicators(synop: &mut Synop, group: &str) -> Option<()> {
+    if !is_code_group(group) {
+        return None;
+    }
+    synop.precipitation_indicator = match code_at(group, 0, 1) {
+        Some(code @ 0..=4) => Some(code as u8),
+        None => None,
+        Some(_) => return None,
+    };
+    synop.station_type = match code_at(group, 1, 2) {
+        Some(code @ 1..=7) => Some(code as u8),
+        None => None,
+        Some(_) => return None,
+    };
+    synop.cloud_base = code_at(group, 2, 3).map(|code| {
+        Quantity::new(
+            CLOUD_BASE_CLASSES[code as usize],
+            Unit::Length(LengthUnit::Meter),
+        )
+    });
+    synop.visibility = match &group[3..] {
+        "//" => None,
+        code => Some(parse_visibility(parse_number(code)?)?),
+    };
+    Some(())
+}
+
+// Lower bound of the visibility class, code table 4377
+fn parse_visibility(code: u32) -> Option<Visibility> {
+    let (meters, qualifier) = match code {
+        0 => (100, Some(RangeQualifier::Below)),
+        1..=50 => (code * 100, None),
+        56..=80 => ((code - 50) * 1000, None),
+        81..=88 => ((code - 74) * 5000, None),
+        89 => (70000, Some(RangeQualifier::Above)),
+        90 => (50, Some(RangeQualifier::Below)),
+        91 => (50, None),
+        92 => (200, None),
+        93 => (500, None),
+        94 => (1000, None),
+        95 => (2000, None),
+        96 => (4000, None),
+        97 => (10000, None),
+        98 => (20000, None),
+        99 => (50000, Some(RangeQualifier::Above)),
+        _ => return None,
+    };
+    Some(Visibility {
+        distance: Quantity::new(meters as f64, Unit::Length(LengthUnit::Meter)),
+        qualifier,
+        direction: None,
+    })
+}
+
+// Parses total cloud cover and wind direction of the Nddff group
+fn parse_cloud_cover_and_direction(group: &str) -> Option<(Option<u8>, Option<WindDirection>)> {
+    if !is_code_group(group) {
+        return None;
+    }
+    let total_cloud_cover = match &group[..1] {
+        "/" => None,
+        code => Some(parse_number(code)? as u8),
+    };
+    let direction = match &group[1..3] {
+        "//" => None,
+        code => Some(match parse_number(code)? {
+            0 => WindDirection::Calm,
+            99 => WindDirection::Variable,
+            tens @ 1..=36 => WindDirection::Degrees(tens as f64 * 10.0),
+            _ => return None,
+        }),
+    };
+    Some((total_cloud_cover, direction))
+}
+
+// Parses speed of the last two digits of a group, reading the following 00fff group for 99 units and more.
+// Speed without wind indicator is malformed.
+fn parse_speed<'a, I>(
+    group: &str,
+    groups: &mut Peekable<I>,
+    unit: Option<SpeedUnit>,
+) -> Option<Option<Quantity>>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let speed = match &group[3..] {
+        "//" => return Some(None),
+        "99" => {
+            let extended = groups.next_if(|next| next.len() == 5 && next.starts_with("00"))?;
+            parse_number(&extended[2..])?
+        }
+        code => parse_number(code)?,
+    };
+    Some(Some(Quantity::new(speed as f64, Unit::Speed(unit?))))
+}
+
+// Parses temperature group with sign digit 0 or 1 followed by tenths of degree
+fn parse_signed_temperature(group: &str) -> Option<Option<Quantity>> {
+    if &group[2..] == "///" {
+        return Some(None);
+    }
+    let tenths = code_at(group, 2, 5)? as f64;
+    match code_at(group, 1, 2)? {
+        0 => Some(Some(celsius(tenths / 10.0))),
+        1 => Some(Some(celsius(-tenths / 10.0))),
+        _ => None,
+    }
+}
+
+// Sea surface temperature sign digit also gives the measurement method, odd digits are negative
+fn parse_sea_temperature(group: &str) -> Option<Option<Quantity>> {
+    if &group[2..] == "///" {
+        return Some(None);
+    }
+    let tenths = code_at(group, 2, 5)? as f64;
+    match code_at(group, 1, 2)? {
+        sign @ 0..=7 if sign % 2 == 1 => Some(Some(celsius(-tenths / 10.0))),
+        0..=7 => Some(Some(celsius(tenths / 10.0))),
+        _ => None,
+    }
+}
+
+// Last four digits of pressure in tenths of hPa, values below 500 hPa belong to the thousands
+fn parse_pressure(group: &str) -> Option<Option<Quantity>> {
+    if &group[1..] == "////" {
+        return Some(None);
+    }
+    let tenths = code_at(group, 1, 5)?;
+    let thousands = if tenths < 5000 { 1000.0 } else { 0.0 };
+    Some(Some(hectopascals(thousands + tenths as f64 / 10.0)))
+}
+
+fn parse_pressure_tendency(group: &str) -> Option<Option<PressureTendency>> {
+    if &group[1..] == "////" {
+        return Some(None);
+    }
+    let characteristic = code_at(group, 1, 2).filter(|code| *code <= 8)? as u8;
+    let tenths = code_at(group, 2, 5)? as f64;
+    let change = match characteristic {
+        0..=3 => tenths / 10.0,
+        4 => 0.0,
+        _ => -tenths / 10.0,
+    };
+    Some(Some(PressureTendency {
+        characteristic,
+        change: hectopascals(change),
+    }))
+}
+
+// Amount code table 3590, 990 is a trace and 991 to 999 are tenths of mm
+fn parse_precipitation(group: &str) -> Option<Option<Precipitation>> {
+    if &group[1..4] == "///" {
+        return Some(None);
+    }
+    let code = code_at(group, 1, 4)?;
+    let millimeters = match code {
+        990 => 0.0,
+        code @ 991..=999 => (code - 990) as f64 / 10.0,
+        code => code as f64,
+    };
+    let period = match &group[4..] {
+        "/" => None,
+        code => {
+            let code = parse_number(code)?;
+            PRECIPITATION_PERIODS
+                .iter()
+                .find(|(candidate, _)| *candidate == code)
+                .map(|(_, hours)| *hours)
+        }
+    };
+    Some(Some(Precipitation {
+        amount: Quantity::new(millimeters, Unit::Length(LengthUnit::Millimeter)),
+        trace: code == 990,
+        period,
+    }))
+}
+
+fn optional_code(group: &str, start: usize, end: usize) -> Option<u8> {
+    code_at(group, start, end).map(|code| code as u8)
+}
+
+fn parse_section_1(synop: &mut Synop, group: &str, indicator: u32) -> Result<(), ParseSynopError> {
+    let group_owned = || group.to_string();
+    match indicator {
+        1 => {
+            synop.temperature = parse_signed_temperature(group)
+                .ok_or_else(|| ParseSynopError::InvalidTemperature(group_owned()))?
+        }
+        2 if group.starts_with("29") => {
+            synop.relative_humidity = match &group[2..] {
+                "///" => None,
+                code => Some(
+                    parse_number(code)
+                        .filter(|humidity| *humidity <= 100)
+                        .ok_or_else(|| ParseSynopError::InvalidTemperature(group_owned()))?
+                        as f64,
+                ),
+            }
+        }
+        2 => {
+            synop.dew_point = parse_signed_temperature(group)
+                .ok_or_else(|| ParseSynopError::InvalidTemperature(group_owned()))?
+        }
+        3 => {
+            synop.station_pressure = parse_pressure(group)
+                .ok_or_else(|| ParseSynopError::InvalidPressure(group_owned()))?
+        }
+        // Geopotential of a standard isobaric surface (4a3hhh) is reported by high stations instead
+        4 if matches!(&group[1..2], "0" | "9" | "/") => {
+            synop.sea_level_pressure = parse_pressure(group)
+                .ok_or_else(|| ParseSynopError::InvalidPressure(group_owned()))?
+        }
+        4 => {}
+        5 => {
+            synop.pressure_tendency = parse_pressure_tendency(group)
+                .ok_or_else(|| ParseSynopError::InvalidPressure(group_owned()))?
+        }
+        6 => {
+            synop.precipitation = parse_precipitation(group)
+                .ok_or_else(|| ParseSynopError::InvalidPrecipitation(group_owned()))?
+        }
+        7 => {
+            synop.present_weather = optional_code(group, 1, 3);
+            synop.past_weather = [optional_code(group, 3, 4), optional_code(group, 4, 5)];
+        }
+        8 => {
+            let clouds = SynopClouds {
+                amount: optional_code(group, 1, 2),
+                low: optional_code(group, 2, 3),
+                middle: optional_code(group, 3, 4),
+                high: optional_code(group, 4, 5),
+            };
+            if clouds.amount.is_some_and(|amount| amount > 9) {
+                return Err(ParseSynopError::InvalidCloud(group_owned()));
+            }
+            synop.clouds = Some(clouds);
+        }
+        9 => {
+            let hour = code_at(group, 1, 3).filter(|hour| *hour <= 23);
+            let minute = code_at(group, 3, 5).filter(|minute| *minute <= 59);
+            let (Some(hour), Some(minute)) = (hour, minute) else {
+                return Err(ParseSynopError::UnexpectedGroup(group_owned()));
+            };
+            synop.exact_time = Some(ReportTime {
+                day: synop.time.day,
+                hour: hour as u8,
+                minute: minute as u8,
+            });
+        }
+        _ => return Err(ParseSynopError::UnexpectedGroup(group_owned())),
+    }
+    Ok(())
+}
+
+// Decodes temperature extremes, snow, sunshine, precipitation, cloud layers and gust, other groups are skipped
+fn parse_section_3<'a, I>(
+    synop: &mut Synop,
+    group: &str,
+    indicator: u32,
+    groups: &mut Peekable<I>,
+) -> Result<(), ParseSynopError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let group_owned = || group.to_string();
+    match indicator {
+        1 => {
+            synop.max_temperature = parse_signed_temperature(group)
+                .ok_or_else(|| ParseSynopError::InvalidTemperature(group_owned()))?
+        }
+        2 => {
+            synop.min_temperature = parse_signed_temperature(group)
+                .ok_or_else(|| ParseSynopError::InvalidTemperature(group_owned()))?
+        }
+        4 => {
+            // 998 is snow cover not continuous and 999 measurement impossible
+            let centimeters = code_at(group, 2, 5).and_then(|code| match code {
+                1..=996 => Some(code as f64),
+                997 => Some(0.0),
+                _ => None,
+            });
+            synop.snow_depth =
+                centimeters.map(|depth| Quantity::new(depth, Unit::Length(LengthUnit::Centimeter)));
+        }
+        // 553SS group of the last hour has more than 24 hours in its place
+        5 if group.starts_with("55") => {
+            if let Some(tenths) = code_at(group, 2, 5).filter(|tenths| *tenths <= 240) {
+                synop.sunshine_duration = Some(tenths as f64 / 10.0);
+            }
+        }
+        6 => {
+            synop.supplementary_precipitation = parse_precipitation(group)
+                .ok_or_else(|| ParseSynopError::InvalidPrecipitation(group_owned()))?
+        }
+        7 => {
+            synop.daily_precipitation = code_at(group, 1, 5).map(|tenths| {
+                // 9999 is a trace
+                let trace = tenths == 9999;
+                let millimeters = if trace { 0.0 } else { tenths as f64 / 10.0 };
+                Precipitation {
+                    amount: Quantity::new(millimeters, Unit::Length(LengthUnit::Millimeter)),
+                    trace,
+                    period: Some(24),
+                }
+            })
+        }
+        8 => {
+            let base = match &group[3..] {
+                "//" => None,
+                code => {
+                    let code = parse_number(code)
+                        .ok_or_else(|| ParseSynopError::InvalidCloud(group_owned()))?;
+                    Some(Quantity::new(
+                        parse_cloud_height(code)
+                            .ok_or_else(|| ParseSynopError::InvalidCloud(group_owned()))?,
+                        Unit::Length(LengthUnit::Meter),
+                    ))
+                }
+            };
+            synop.cloud_layers.push(SynopCloudLayer {
+                amount: optional_code(group, 1, 2),
+                genus: optional_code(group, 2, 3),
+                base,
+            });
+        }
+        9 if group.starts_with("911") => {
+            let unit = synop.wind_indicator.map(|indicator| indicator.unit());
+            let gust = parse_speed(group, groups, unit)
+                .ok_or_else(|| ParseSynopError::InvalidWind(group_owned()))?;
+            if let Some(wind) = &mut synop.wind {
+                wind.gust = gust;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// Lower bound of the cloud height class in meters, code table 1677
+fn parse_cloud_height(code: u32) -> Option<f64> {
+    let meters = match code {
+        0..=50 => code as f64 * 30.0,
+        56..=80 => (code - 50) as f64 * 300.0,
+        81..=88 => (code - 80) as f64 * 1500.0 + 9000.0,
+        89 => 21000.0,
+        90..=99 => CLOUD_BASE_CLASSES[code as usize - 90],
+        _ => return None,
+    };
+    Some(meters)
+}
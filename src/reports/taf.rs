@@ -0,0 +1,711 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::reports::metar::{
+    is_cloud, is_wind, parse_cloud, parse_day_time, parse_metric_visibility, parse_number,
+    parse_station, parse_statute_visibility, parse_temperature, parse_time, parse_weather,
+    parse_wind,
+};
+use crate::reports::{CloudLayer, ReportTime, Visibility, Weather, Wind};
+use crate::{LengthUnit, Quantity, Unit};
+
+/// Terminal aerodrome forecast.
+///
+/// Parsed with [`FromStr`] from the WMO FM 51 (TAF) code, including the US variant with statute miles.
+/// Forecast conditions use the same types as [`Metar`](crate::reports::Metar), change groups (FM, BECMG,
+/// TEMPO and PROB) are kept in order of the report. Use [`Taf::timeline`] for the prevailing conditions
+/// over the validity period and [`Taf::forecast_at`] for conditions expected at a given time.
+///
+/// As the report carries no month, times with a day before the first day of validity belong to the next month.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::reports::{ChangeKind, CloudCover, Taf};
+/// use meteo_tools::WindDirection;
+///
+/// let taf: Taf = "TAF LKPR 121100Z 1212/1312 24010KT 9999 SCT030 \
+///     BECMG 1218/1220 VRB03KT \
+///     TEMPO 1300/1306 3000 BR BKN008 \
+///     FM131000 27015G25KT CAVOK"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(taf.station, "LKPR");
+/// assert_eq!(taf.validity.to.hour, 12);
+/// assert_eq!(taf.conditions.clouds[0].cover, CloudCover::Scattered);
+/// assert_eq!(taf.changes[1].kind, ChangeKind::Temporary);
+/// assert_eq!(taf.changes[2].conditions.wind.unwrap().direction, WindDirection::Degrees(270.0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Taf {
+    /// ICAO location indicator of the aerodrome.
+    pub station: String,
+    /// Day and time of issue in UTC.
+    pub issued: ReportTime,
+    /// Amended, corrected, cancelled or missing forecast.
+    pub modifier: Option<TafModifier>,
+    /// Period the forecast is valid for.
+    pub validity: ValidityPeriod,
+    /// Conditions expected from the start of the validity period.
+    pub conditions: ForecastConditions,
+    /// Forecast maximum temperatures (TX groups), one for each day of a validity period longer than a day.
+    pub max_temperatures: Vec<TemperatureForecast>,
+    /// Forecast minimum temperatures (TN groups), one for each day of a validity period longer than a day.
+    pub min_temperatures: Vec<TemperatureForecast>,
+    /// Change groups in order of the report.
+    pub changes: Vec<ChangeGroup>,
+    /// Remarks following `RMK`, undecoded.
+    pub remarks: Option<String>,
+}
+
+/// Modifier of a terminal aerodrome forecast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TafModifier {
+    /// Amended forecast (AMD).
+    Amended,
+    /// Corrected forecast (COR).
+    Corrected,
+    /// Cancelled forecast (CNL).
+    Cancelled,
+    /// Missing forecast (NIL).
+    Nil,
+}
+
+/// Period between two times, the end is exclusive.
+///
+/// Hour 24 is the end of the day, as used in TAF validity periods.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidityPeriod {
+    /// Start of the period, inclusive.
+    pub from: ReportTime,
+    /// End of the period, exclusive. Hour 24 ends the day, e.g. `0824` is the end of the 8th.
+    pub to: ReportTime,
+}
+
+/// Weather conditions of a forecast or a change group. Elements not forecast are `None` or empty.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForecastConditions {
+    /// Surface wind.
+    pub wind: Option<Wind>,
+    /// Prevailing visibility.
+    pub visibility: Option<Visibility>,
+    /// Visibility, weather and clouds are OK (CAVOK).
+    pub cavok: bool,
+    /// Forecast weather.
+    pub weather: Vec<Weather>,
+    /// End of significant weather (NSW).
+    pub no_significant_weather: bool,
+    /// Cloud layers, vertical visibility or the reason there are no clouds forecast.
+    pub clouds: Vec<CloudLayer>,
+    /// Non-convective low level wind shear (WS group of US forecasts).
+    pub wind_shear: Option<WindShear>,
+}
+
+/// Non-convective low level wind shear, such as `WS020/24045KT`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindShear {
+    /// Height of the top of the wind shear layer above ground in feet.
+    pub height: Quantity,
+    /// Wind at the top of the layer.
+    pub wind: Wind,
+}
+
+/// Kind of a change group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    /// Conditions change completely at the start of the period (FM).
+    From,
+    /// Conditions change gradually during the period and persist after it (BECMG).
+    Becoming,
+    /// Temporary fluctuations during the period (TEMPO).
+    Temporary,
+    /// Conditions with given probability during the period (PROB without TEMPO).
+    Probable,
+}
+
+/// Change group of a terminal aerodrome forecast.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeGroup {
+    /// Kind of the change.
+    pub kind: ChangeKind,
+    /// Probability in percent of PROB groups, 30 or 40.
+    pub probability: Option<u8>,
+    /// Period of the change. FM groups last until the next FM group or the end of validity.
+    pub period: ValidityPeriod,
+    /// Changed conditions.
+    pub conditions: ForecastConditions,
+}
+
+/// Forecast extreme temperature with its time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TemperatureForecast {
+    /// Temperature in degrees Celsius.
+    pub temperature: Quantity,
+    /// Time the temperature is expected.
+    pub time: ReportTime,
+}
+
+/// Prevailing conditions over a part of the validity period, see [`Taf::timeline`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForecastPeriod {
+    /// Part of the validity period the conditions prevail in.
+    pub period: ValidityPeriod,
+    /// Prevailing conditions, with FM and BECMG groups up to the period applied.
+    pub conditions: ForecastConditions,
+}
+
+/// Conditions expected at a given time, see [`Taf::forecast_at`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Forecast {
+    /// Prevailing conditions.
+    pub prevailing: ForecastConditions,
+    /// Temporary and probable changes and gradual changes in progress at the time.
+    pub possible: Vec<ChangeGroup>,
+}
+
+/// Error returned when a TAF cannot be parsed. Carries the offending group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseTafError {
+    /// Report is empty.
+    Empty,
+    /// Report ends before the station, issue time and validity period.
+    Incomplete,
+    /// Station is not a four character ICAO location indicator.
+    InvalidStation(String),
+    /// Issue time is not `ddhhmmZ` or is out of range.
+    InvalidTime(String),
+    /// Validity period is not `ddhh/ddhh` or is out of range.
+    InvalidValidity(String),
+    /// Change group has malformed time, period or probability, or its period is outside of the validity period.
+    InvalidChangeGroup(String),
+    /// Wind group is malformed.
+    InvalidWind(String),
+    /// Visibility group is malformed.
+    InvalidVisibility(String),
+    /// Weather group contains unknown descriptor or phenomenon.
+    InvalidWeather(String),
+    /// Cloud group is malformed.
+    InvalidCloud(String),
+    /// Maximum or minimum temperature group is malformed.
+    InvalidTemperature(String),
+    /// Group is not recognised.
+    UnexpectedGroup(String),
+}
+
+impl fmt::Display for ParseTafError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, group) = match self {
+            ParseTafError::Empty => return f.write_str("empty report"),
+            ParseTafError::Incomplete => {
+                return f.write_str("report has no station, issue time or validity")
+            }
+            ParseTafError::InvalidStation(group) => ("station", group),
+            ParseTafError::InvalidTime(group) => ("time", group),
+            ParseTafError::InvalidValidity(group) => ("validity", group),
+            ParseTafError::InvalidChangeGroup(group) => ("change", group),
+            ParseTafError::InvalidWind(group) => ("wind", group),
+            ParseTafError::InvalidVisibility(group) => ("visibility", group),
+            ParseTafError::InvalidWeather(group) => ("weather", group),
+            ParseTafError::InvalidCloud(group) => ("cloud", group),
+            ParseTafError::InvalidTemperature(group) => ("temperature", group),
+            ParseTafError::UnexpectedGroup(group) => {
+                return write!(f, "unexpected group `{}`", group)
+            }
+        };
+        write!(f, "invalid {} group `{}`", kind, group)
+    }
+}
+
+impl std::error::Error for ParseTafError {}
+
+impl Taf {
+    /// Returns prevailing conditions over the validity period.
+    ///
+    /// Each FM group replaces all conditions from its time, each BECMG group changes the conditions
+    /// it forecasts from the end of its period. TEMPO and PROB groups do not change prevailing conditions.
+    /// Missing forecasts (NIL) have an empty timeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::Taf;
+    /// use meteo_tools::WindDirection;
+    ///
+    /// let taf: Taf = "TAF LKPR 121100Z 1212/1312 24010KT 9999 SCT030 BECMG 1218/1220 VRB03KT FM131000 27015KT CAVOK"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let timeline = taf.timeline();
+    ///
+    /// assert_eq!(timeline.len(), 3);
+    /// assert_eq!(timeline[1].period.from.hour, 20);
+    /// assert_eq!(timeline[1].conditions.wind.unwrap().direction, WindDirection::Variable);
+    /// assert_eq!(timeline[1].conditions.clouds, taf.conditions.clouds);
+    /// assert!(timeline[2].conditions.cavok);
+    /// ```
+    pub fn timeline(&self) -> Vec<ForecastPeriod> {
+        if self.modifier == Some(TafModifier::Nil) {
+            return Vec::new();
+        }
+        let mut changes: Vec<&ChangeGroup> = self
+            .changes
+            .iter()
+            .filter(|change| matches!(change.kind, ChangeKind::From | ChangeKind::Becoming))
+            .collect();
+        changes.sort_by_key(|change| self.time_key(&change_start(change)));
+
+        let mut timeline = vec![ForecastPeriod {
+            period: self.validity,
+            conditions: self.conditions.clone(),
+        }];
+        for change in changes {
+            let start = change_start(change);
+            let Some(current) = timeline.last_mut() else {
+                break;
+            };
+            if self.time_key(&start) < self.time_key(&current.period.from)
+                || self.time_key(&start) >= self.time_key(&self.validity.to)
+            {
+                continue;
+            }
+            let conditions = match change.kind {
+                ChangeKind::From => change.conditions.clone(),
+                _ => {
+                    let mut conditions = current.conditions.clone();
+                    conditions.apply(&change.conditions);
+                    conditions
+                }
+            };
+            if current.period.from == start {
+                current.conditions = conditions;
+            } else {
+                current.period.to = start;
+                timeline.push(ForecastPeriod {
+                    period: ValidityPeriod {
+                        from: start,
+                        to: self.validity.to,
+                    },
+                    conditions,
+                });
+            }
+        }
+        if let Some(last) = timeline.last_mut() {
+            last.period.to = self.validity.to;
+        }
+        timeline
+    }
+
+    /// Returns conditions expected at given time, or `None` outside of the validity period.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{ChangeKind, ReportTime, Taf};
+    ///
+    /// let taf: Taf = "TAF LKPR 121100Z 1212/1312 24010KT 9999 SCT030 PROB30 TEMPO 1300/1306 0800 FG VV002"
+    ///     .parse()
+    ///     .unwrap();
+    ///
+    /// let forecast = taf.forecast_at(ReportTime { day: 13, hour: 4, minute: 30 }).unwrap();
+    ///
+    /// assert_eq!(forecast.prevailing, taf.conditions);
+    /// assert_eq!(forecast.possible[0].kind, ChangeKind::Temporary);
+    /// assert_eq!(forecast.possible[0].probability, Some(30));
+    /// assert_eq!(taf.forecast_at(ReportTime { day: 13, hour: 12, minute: 0 }), None);
+    /// ```
+    pub fn forecast_at(&self, time: ReportTime) -> Option<Forecast> {
+        if !self.contains(&self.validity, &time) {
+            return None;
+        }
+        let prevailing = self
+            .timeline()
+            .into_iter()
+            .find(|period| self.contains(&period.period, &time))?
+            .conditions;
+        let possible = self
+            .changes
+            .iter()
+            .filter(|change| {
+                change.kind != ChangeKind::From && self.contains(&change.period, &time)
+            })
+            .cloned()
+            .collect();
+        Some(Forecast {
+            prevailing,
+            possible,
+        })
+    }
+
+    fn contains(&self, period: &ValidityPeriod, time: &ReportTime) -> bool {
+        let key = self.time_key(time);
+        self.time_key(&period.from) <= key && key < self.time_key(&period.to)
+    }
+
+    // Whether the period lies within the validity period
+    fn covers(&self, period: &ValidityPeriod) -> bool {
+        let (from, to) = (self.time_key(&period.from), self.time_key(&period.to));
+        self.time_key(&self.validity.from) <= from
+            && from <= to
+            && to <= self.time_key(&self.validity.to)
+    }
+
+    // Orders times within the validity period, days before the first day of validity are in the next month
+    fn time_key(&self, time: &ReportTime) -> u32 {
+        let month = u32::from(time.day < self.validity.from.day);
+        ((month * 32 + u32::from(time.day)) * 24 + u32::from(time.hour)) * 60
+            + u32::from(time.minute)
+    }
+}
+
+// Time prevailing conditions change, the start of FM groups and the end of BECMG groups
+fn change_start(change: &ChangeGroup) -> ReportTime {
+    match change.kind {
+        ChangeKind::From => change.period.from,
+        _ => change.period.to,
+    }
+}
+
+impl ForecastConditions {
+    // Overlays conditions forecast by a change group
+    fn apply(&mut self, change: &ForecastConditions) {
+        if change.wind.is_some() {
+            self.wind = change.wind;
+        }
+        if change.wind_shear.is_some() {
+            self.wind_shear = change.wind_shear;
+        }
+        if change.cavok {
+            *self = ForecastConditions {
+                wind: self.wind,
+                wind_shear: self.wind_shear,
+                cavok: true,
+                ..Default::default()
+            };
+            return;
+        }
+        if change.visibility.is_some() {
+            self.visibility = change.visibility;
+            self.cavok = false;
+        }
+        if change.no_significant_weather {
+            self.weather.clear();
+        } else if !change.weather.is_empty() {
+            self.weather.clone_from(&change.weather);
+            self.cavok = false;
+        }
+        if !change.clouds.is_empty() {
+            self.clouds.clone_from(&change.clouds);
+            self.cavok = false;
+        }
+    }
+}
+
+impl FromStr for Taf {
+    type Err = ParseTafError;
+
+    /// Parses a forecast, optionally starting with `TAF` and ending with `=`.
+    ///
+    /// Change groups with a period outside of the validity period are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::reports::{ParseTafError, Taf};
+    ///
+    /// let taf: Taf = "TAF KJFK 121130Z 1212/1318 24010KT P6SM SCT030 WS020/24045KT TX25/1219Z TX27/1318Z TN14/1310Z"
+    ///     .parse()
+    ///     .unwrap();
+    /// let wind_shear = taf.conditions.wind_shear.unwrap();
+    ///
+    /// assert_eq!(wind_shear.height.value, 2000.0);
+    /// assert_eq!(wind_shear.wind.speed.value, 45.0);
+    /// assert_eq!(taf.max_temperatures.len(), 2);
+    ///
+    /// let error = "TAF LKPR 121100Z 1212/1312 24010KT 9999 SCT030 TEMPO 1310/1316 3000 BR".parse::<Taf>();
+    ///
+    /// assert_eq!(error, Err(ParseTafError::InvalidChangeGroup("TEMPO 1310/1316".to_string())));
+    /// ```
+    fn from_str(report: &str) -> Result<Self, Self::Err> {
+        let report = report.trim().trim_end_matches('=');
+        let groups: Vec<&str> = report.split_whitespace().collect();
+        if groups.is_empty() {
+            return Err(ParseTafError::Empty);
+        }
+        let (body, remarks) = match groups.iter().position(|group| *group == "RMK") {
+            Some(index) => (&groups[..index], Some(groups[index + 1..].join(" "))),
+            None => (&groups[..], None),
+        };
+
+        let mut taf = Taf {
+            remarks,
+            ..Default::default()
+        };
+        let mut groups = body.iter().copied().peekable();
+        groups.next_if_eq(&"TAF");
+        while let Some(modifier) = groups.peek().and_then(|group| parse_modifier(group)) {
+            taf.modifier = Some(modifier);
+            groups.next();
+        }
+        let station = groups.next().ok_or(ParseTafError::Incomplete)?;
+        taf.station = parse_station(station)
+            .ok_or_else(|| ParseTafError::InvalidStation(station.to_string()))?;
+        let issued = groups.next().ok_or(ParseTafError::Incomplete)?;
+        taf.issued =
+            parse_time(issued).ok_or_else(|| ParseTafError::InvalidTime(issued.to_string()))?;
+        if groups.next_if_eq(&"NIL").is_some() {
+            taf.modifier = Some(TafModifier::Nil);
+            return Ok(taf);
+        }
+        let validity = groups.next().ok_or(ParseTafError::Incomplete)?;
+        taf.validity = parse_period(validity)
+            .ok_or_else(|| ParseTafError::InvalidValidity(validity.to_string()))?;
+
+        let mut change: Option<ChangeGroup> = None;
+        while let Some(group) = groups.next() {
+            let error = |make: fn(String) -> ParseTafError| make(group.to_string());
+            if group == "NIL" {
+                taf.modifier = Some(TafModifier::Nil);
+                continue;
+            }
+            if let Some(modifier) = parse_modifier(group) {
+                taf.modifier = Some(modifier);
+                continue;
+            }
+            let new_change = if let Some(time) = group.strip_prefix("FM") {
+                let from = parse_day_time(time)
+                    .filter(|from| {
+                        taf.covers(&ValidityPeriod {
+                            from: *from,
+                            ..taf.validity
+                        })
+                    })
+                    .ok_or_else(|| error(ParseTafError::InvalidChangeGroup))?;
+                Some(ChangeGroup {
+                    kind: ChangeKind::From,
+                    probability: None,
+                    period: ValidityPeriod {
+                        from,
+                        to: taf.validity.to,
+                    },
+                    conditions: ForecastConditions::default(),
+                })
+            } else if let Some(probability) = group.strip_prefix("PROB") {
+                let probability = parse_number(probability)
+                    .filter(|probability| matches!(probability, 30 | 40))
+                    .ok_or_else(|| error(ParseTafError::InvalidChangeGroup))?;
+                let kind = match groups.next_if_eq(&"TEMPO") {
+                    Some(_) => ChangeKind::Temporary,
+                    None => ChangeKind::Probable,
+                };
+                Some(parse_change_period(
+                    &taf,
+                    kind,
+                    Some(probability as u8),
+                    groups.next(),
+                    group,
+                )?)
+            } else if group == "BECMG" {
+                Some(parse_change_period(
+                    &taf,
+                    ChangeKind::Becoming,
+                    None,
+                    groups.next(),
+                    group,
+                )?)
+            } else if group == "TEMPO" {
+                Some(parse_change_period(
+                    &taf,
+                    ChangeKind::Temporary,
+                    None,
+                    groups.next(),
+                    group,
+                )?)
+            } else {
+                None
+            };
+            if let Some(new_change) = new_change {
+                taf.changes.extend(change.replace(new_change));
+                continue;
+            }
+
+            if let Some(forecast) = group.strip_prefix("TX") {
+                taf.max_temperatures.push(
+                    parse_temperature_forecast(forecast)
+                        .ok_or_else(|| error(ParseTafError::InvalidTemperature))?,
+                );
+                continue;
+            }
+            if let Some(forecast) = group.strip_prefix("TN") {
+                taf.min_temperatures.push(
+                    parse_temperature_forecast(forecast)
+                        .ok_or_else(|| error(ParseTafError::InvalidTemperature))?,
+                );
+                continue;
+            }
+            let conditions = match &mut change {
+                Some(change) => &mut change.conditions,
+                None => &mut taf.conditions,
+            };
+            // Whole statute miles are a separate group before the fraction, as in `1 1/2SM`
+            let next_is_fraction = groups
+                .peek()
+                .is_some_and(|next| next.ends_with("SM") && next.contains('/'));
+            if group.len() <= 2 && parse_number(group).is_some() && next_is_fraction {
+                let fraction = groups.next().unwrap_or_default();
+                let combined = format!("{} {}", group, fraction);
+                conditions.visibility = Some(
+                    parse_statute_visibility(&combined)
+                        .ok_or(ParseTafError::InvalidVisibility(combined))?,
+                );
+            } else {
+                parse_conditions_group(conditions, group)?;
+            }
+        }
+        taf.changes.extend(change);
+
+        // FM groups last until the next FM group
+        let starts: Vec<ReportTime> = taf
+            .changes
+            .iter()
+            .filter(|change| change.kind == ChangeKind::From)
+            .map(|change| change.period.from)
+            .collect();
+        let mut from_groups = taf
+            .changes
+            .iter_mut()
+            .filter(|change| change.kind == ChangeKind::From);
+        for next_start in starts.iter().skip(1) {
+            if let Some(change) = from_groups.next() {
+                change.period.to = *next_start;
+            }
+        }
+        Ok(taf)
+    }
+}
+
+fn parse_modifier(group: &str) -> Option<TafModifier> {
+    match group {
+        "AMD" => Some(TafModifier::Amended),
+        "COR" => Some(TafModifier::Corrected),
+        "CNL" => Some(TafModifier::Cancelled),
+        _ => None,
+    }
+}
+
+// Parses period such as `1212/1312`, hour 24 is accepted as the end of the day
+fn parse_period(group: &str) -> Option<ValidityPeriod> {
+    let (from, to) = group.split_once('/')?;
+    Some(ValidityPeriod {
+        from: parse_day_hour(from)?,
+        to: parse_day_hour(to)?,
+    })
+}
+
+fn parse_day_hour(digits: &str) -> Option<ReportTime> {
+    if digits.len() != 4 {
+        return None;
+    }
+    parse_number(digits)?;
+    let time = ReportTime {
+        day: digits[..2].parse().ok()?,
+        hour: digits[2..].parse().ok()?,
+        minute: 0,
+    };
+    ((1..=31).contains(&time.day) && time.hour <= 24).then_some(time)
+}
+
+// Parses period of BECMG, TEMPO and PROB groups, which must lie within the validity period
+fn parse_change_period(
+    taf: &Taf,
+    kind: ChangeKind,
+    probability: Option<u8>,
+    period: Option<&str>,
+    group: &str,
+) -> Result<ChangeGroup, ParseTafError> {
+    let period = period.ok_or_else(|| ParseTafError::InvalidChangeGroup(group.to_string()))?;
+    let period = parse_period(period)
+        .filter(|period| taf.covers(period))
+        .ok_or_else(|| ParseTafError::InvalidChangeGroup(format!("{} {}", group, period)))?;
+    Ok(ChangeGroup {
+        kind,
+        probability,
+        period,
+        conditions: ForecastConditions::default(),
+    })
+}
+
+// Parses temperature and time of TX and TN groups without the prefix, such as `25/1214Z` or `M02/1306Z`
+fn parse_temperature_forecast(forecast: &str) -> Option<TemperatureForecast> {
+    let (temperature, time) = forecast.split_once('/')?;
+    Some(TemperatureForecast {
+        temperature: parse_temperature(temperature)??,
+        time: parse_day_hour(time.strip_suffix('Z')?)?,
+    })
+}
+
+// Parses wind shear with the height in hundreds of feet, such as `WS020/24045KT`
+fn parse_wind_shear(group: &str) -> Option<WindShear> {
+    let (height, wind) = group.strip_prefix("WS")?.split_once('/')?;
+    let height = parse_number(height).filter(|_| height.len() == 3)?;
+    Some(WindShear {
+        height: Quantity::new(height as f64 * 100.0, Unit::Length(LengthUnit::Foot)),
+        wind: parse_wind(wind)??,
+    })
+}
+
+// Parses one group of forecast conditions, recognised by its shape
+fn parse_conditions_group(
+    conditions: &mut ForecastConditions,
+    group: &str,
+) -> Result<(), ParseTafError> {
+    let error = |make: fn(String) -> ParseTafError| make(group.to_string());
+    if !group.is_ascii() {
+        return Err(error(ParseTafError::UnexpectedGroup));
+    }
+    match group {
+        "CAVOK" => conditions.cavok = true,
+        _ if group.starts_with("WS") => {
+            conditions.wind_shear =
+                Some(parse_wind_shear(group).ok_or_else(|| error(ParseTafError::InvalidWind))?);
+        }
+        "NSW" => conditions.no_significant_weather = true,
+        _ if is_wind(group) => {
+            conditions.wind = parse_wind(group).ok_or_else(|| error(ParseTafError::InvalidWind))?;
+        }
+        _ if group.ends_with("SM") => {
+            conditions.visibility = Some(
+                parse_statute_visibility(group)
+                    .ok_or_else(|| error(ParseTafError::InvalidVisibility))?,
+            );
+        }
+        _ if group.len() >= 4 && parse_number(&group[..4]).is_some() => {
+            conditions.visibility = Some(
+                parse_metric_visibility(group)
+                    .ok_or_else(|| error(ParseTafError::InvalidVisibility))?,
+            );
+        }
+        _ if is_cloud(group) => {
+            let layer = parse_cloud(group).ok_or_else(|| error(ParseTafError::InvalidCloud))?;
+            conditions.clouds.push(layer);
+        }
+        _ => match parse_weather(group) {
+            Some(weather) => conditions.weather.push(weather),
+            None if group.starts_with(['+', '-']) || group.starts_with("VC") => {
+                return Err(error(ParseTafError::InvalidWeather))
+            }
+            None => return Err(error(ParseTafError::UnexpectedGroup)),
+        },
+    }
+    Ok(())
+}
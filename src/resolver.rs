@@ -0,0 +1,437 @@
+use core::fmt;
+
+use crate::calculations::dew_point::calculate_exact_pressure_offset;
+use crate::constants::constants::{
+    AVG_ATMOSPHERIC_PRESSURE, BAROMETRIC_EXPONENT, GAS_CONSTANT, KELVIN_DEGREE_CONSTANT,
+    LATENT_HEAT_OF_VAPORIZATION, MOLAR_MASS_RATIO, MOLAR_MASS_WATER, PSYCHROMETER_COEFFICIENT,
+    STANDARD_CONDENSATION_POINT,
+};
+use crate::{
+    celsius_absolute_humidity, celsius_heat_index, celsius_humidex, celsius_mixing_ratio,
+    common_celsius_dew_point, common_celsius_humidex, common_celsius_mixing_ratio, meteo_round,
+    saturation_vapor_pressure, Float,
+};
+
+/// Variable known to the [`Resolver`].
+///
+/// Temperatures are in degrees Celsius, pressures in hPa, relative humidity in percent,
+/// mixing ratio in kg/kg and absolute humidity in kg/m³.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Variable {
+    /// Air temperature.
+    Temperature,
+    /// Relative humidity.
+    RelativeHumidity,
+    /// Dew point temperature.
+    DewPoint,
+    /// Psychrometric wet bulb temperature.
+    WetBulbTemperature,
+    /// Station atmospheric pressure, never derived.
+    Pressure,
+    /// Saturation vapor pressure over water.
+    SaturationVaporPressure,
+    /// Actual vapor pressure.
+    VaporPressure,
+    /// Mixing ratio of water vapor to dry air.
+    MixingRatio,
+    /// Mass of water vapor per volume of air.
+    AbsoluteHumidity,
+    /// Heat index.
+    HeatIndex,
+    /// Humidex.
+    Humidex,
+}
+
+const VARIABLE_COUNT: usize = 11;
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Variable::Temperature => "temperature",
+            Variable::RelativeHumidity => "relative humidity",
+            Variable::DewPoint => "dew point",
+            Variable::WetBulbTemperature => "wet bulb temperature",
+            Variable::Pressure => "pressure",
+            Variable::SaturationVaporPressure => "saturation vapor pressure",
+            Variable::VaporPressure => "vapor pressure",
+            Variable::MixingRatio => "mixing ratio",
+            Variable::AbsoluteHumidity => "absolute humidity",
+            Variable::HeatIndex => "heat index",
+            Variable::Humidex => "humidex",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Error returned when a variable cannot be derived from the known variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveError {
+    /// Known variables are not sufficient to derive the requested variable.
+    Insufficient(Variable),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::Insufficient(variable) => {
+                write!(f, "insufficient inputs to derive {}", variable)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResolveError {}
+
+/// Derives any variable from whichever sufficient subset of variables is known.
+///
+/// Known variables are chained through the crate's functions until the requested variable is found,
+/// e.g. temperature and wet bulb temperature give relative humidity, which gives mixing ratio.
+/// Derived variables are kept, so resolving further variables reuses them.
+///
+/// Pressure is never derived. When it is not known, the common atmospheric pressure is used,
+/// the same way as the `common_` functions.
+///
+/// # Examples
+///
+/// ```
+/// use meteo_tools::{ResolveError, Resolver, Variable};
+///
+/// let mut resolver = Resolver::new()
+///     .with(Variable::Temperature, 25.0)
+///     .with(Variable::WetBulbTemperature, 20.0);
+///
+/// let relative_humidity = resolver.resolve(Variable::RelativeHumidity);
+/// let heat_index = Resolver::new().with(Variable::Temperature, 25.0).resolve(Variable::HeatIndex);
+///
+/// assert_eq!(relative_humidity, Ok(63.2387));
+/// assert_eq!(heat_index, Err(ResolveError::Insufficient(Variable::HeatIndex)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Resolver {
+    values: [Option<f64>; VARIABLE_COUNT],
+}
+
+// Derivation of one variable from others, inputs are guaranteed to be known when `compute` is called
+struct Rule {
+    output: Variable,
+    inputs: &'static [Variable],
+    compute: fn(&Resolver) -> f64,
+}
+
+const RULES: [Rule; 14] = [
+    Rule {
+        output: Variable::RelativeHumidity,
+        inputs: &[Variable::Temperature, Variable::DewPoint],
+        compute: |known| {
+            let dew_point = known.common_dew_point(known.value(Variable::DewPoint));
+            meteo_round(&magnus_relative_humidity(
+                known.value(Variable::Temperature),
+                dew_point,
+            ))
+        },
+    },
+    Rule {
+        output: Variable::RelativeHumidity,
+        inputs: &[Variable::Temperature, Variable::VaporPressure],
+        compute: |known| {
+            let temperature = known.value(Variable::Temperature);
+            let relative_humidity = 100.0 * known.value(Variable::VaporPressure)
+                / saturation_vapor_pressure(&temperature);
+            meteo_round(&relative_humidity)
+        },
+    },
+    Rule {
+        output: Variable::RelativeHumidity,
+        inputs: &[Variable::Temperature, Variable::WetBulbTemperature],
+        compute: |known| {
+            let temperature = known.value(Variable::Temperature);
+            let wet_bulb = known.value(Variable::WetBulbTemperature);
+            let vapor_pressure =
+                psychrometric_vapor_pressure(temperature, wet_bulb, known.pressure());
+            meteo_round(&(100.0 * vapor_pressure / saturation_vapor_pressure(&temperature)))
+        },
+    },
+    Rule {
+        output: Variable::Temperature,
+        inputs: &[Variable::DewPoint, Variable::RelativeHumidity],
+        compute: |known| {
+            let dew_point = known.common_dew_point(known.value(Variable::DewPoint));
+            let latent_heat = LATENT_HEAT_OF_VAPORIZATION;
+            let gamma = latent_heat * dew_point / (STANDARD_CONDENSATION_POINT + dew_point)
+                - Float::ln(known.value(Variable::RelativeHumidity) / 100.0);
+            meteo_round(&(STANDARD_CONDENSATION_POINT * gamma / (latent_heat - gamma)))
+        },
+    },
+    Rule {
+        output: Variable::VaporPressure,
+        inputs: &[Variable::MixingRatio],
+        compute: |known| {
+            let mixing_ratio = known.value(Variable::MixingRatio);
+            meteo_round(&(mixing_ratio * known.pressure() / (MOLAR_MASS_RATIO + mixing_ratio)))
+        },
+    },
+    Rule {
+        output: Variable::VaporPressure,
+        inputs: &[Variable::Temperature, Variable::AbsoluteHumidity],
+        compute: |known| {
+            let temperature = known.value(Variable::Temperature) + KELVIN_DEGREE_CONSTANT;
+            let vapor_pressure_pa =
+                known.value(Variable::AbsoluteHumidity) * GAS_CONSTANT * temperature
+                    / MOLAR_MASS_WATER;
+            meteo_round(&(vapor_pressure_pa / 100.0))
+        },
+    },
+    Rule {
+        output: Variable::VaporPressure,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            let vapor_pressure = saturation_vapor_pressure(&known.value(Variable::Temperature))
+                * (known.value(Variable::RelativeHumidity) / 100.0);
+            meteo_round(&vapor_pressure)
+        },
+    },
+    Rule {
+        output: Variable::SaturationVaporPressure,
+        inputs: &[Variable::Temperature],
+        compute: |known| {
+            meteo_round(&saturation_vapor_pressure(
+                &known.value(Variable::Temperature),
+            ))
+        },
+    },
+    Rule {
+        output: Variable::DewPoint,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            let dew_point = common_celsius_dew_point(
+                &known.value(Variable::Temperature),
+                &known.value(Variable::RelativeHumidity),
+            );
+            match known.get(Variable::Pressure) {
+                Some(pressure) => calculate_exact_pressure_offset(&pressure, &dew_point),
+                None => dew_point,
+            }
+        },
+    },
+    Rule {
+        output: Variable::WetBulbTemperature,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            let temperature = known.value(Variable::Temperature);
+            let vapor_pressure = saturation_vapor_pressure(&temperature)
+                * (known.value(Variable::RelativeHumidity) / 100.0);
+            meteo_round(&psychrometric_wet_bulb(
+                temperature,
+                vapor_pressure,
+                known.pressure(),
+            ))
+        },
+    },
+    Rule {
+        output: Variable::MixingRatio,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            let temperature = known.value(Variable::Temperature);
+            let relative_humidity = known.value(Variable::RelativeHumidity);
+            match known.get(Variable::Pressure) {
+                Some(pressure) => celsius_mixing_ratio(&temperature, &relative_humidity, &pressure),
+                None => common_celsius_mixing_ratio(&temperature, &relative_humidity),
+            }
+        },
+    },
+    Rule {
+        output: Variable::AbsoluteHumidity,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            celsius_absolute_humidity(
+                &known.value(Variable::Temperature),
+                &known.value(Variable::RelativeHumidity),
+            )
+        },
+    },
+    Rule {
+        output: Variable::HeatIndex,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            celsius_heat_index(
+                &known.value(Variable::Temperature),
+                &known.value(Variable::RelativeHumidity),
+            )
+        },
+    },
+    Rule {
+        output: Variable::Humidex,
+        inputs: &[Variable::Temperature, Variable::RelativeHumidity],
+        compute: |known| {
+            let temperature = known.value(Variable::Temperature);
+            let relative_humidity = known.value(Variable::RelativeHumidity);
+            match known.get(Variable::Pressure) {
+                Some(pressure) => celsius_humidex(&temperature, &relative_humidity, &pressure),
+                None => common_celsius_humidex(&temperature, &relative_humidity),
+            }
+        },
+    },
+];
+
+impl Resolver {
+    /// Creates resolver with no known variables.
+    pub fn new() -> Self {
+        Resolver::default()
+    }
+
+    /// Sets known value of the variable.
+    pub fn with(mut self, variable: Variable, value: f64) -> Self {
+        self.set(variable, value);
+        self
+    }
+
+    /// Sets known value of the variable.
+    pub fn set(&mut self, variable: Variable, value: f64) {
+        self.values[variable as usize] = Some(value);
+    }
+
+    /// Returns value of the variable if it is known or was already derived.
+    pub fn get(&self, variable: Variable) -> Option<f64> {
+        self.values[variable as usize]
+    }
+
+    /// Returns whether the variable can be derived from the known variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{Resolver, Variable};
+    ///
+    /// let resolver = Resolver::new()
+    ///     .with(Variable::DewPoint, 15.0)
+    ///     .with(Variable::RelativeHumidity, 60.0);
+    ///
+    /// assert!(resolver.can_resolve(Variable::Humidex));
+    /// assert!(!resolver.can_resolve(Variable::Pressure));
+    /// ```
+    pub fn can_resolve(&self, variable: Variable) -> bool {
+        let mut known = self.values.map(|value| value.is_some());
+        loop {
+            if known[variable as usize] {
+                return true;
+            }
+            let mut progress = false;
+            for rule in RULES.iter() {
+                if !known[rule.output as usize]
+                    && rule.inputs.iter().all(|input| known[*input as usize])
+                {
+                    known[rule.output as usize] = true;
+                    progress = true;
+                }
+            }
+            if !progress {
+                return false;
+            }
+        }
+    }
+
+    /// Derives the variable by chaining the crate's functions over the known variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use meteo_tools::{Resolver, Variable};
+    ///
+    /// let mut resolver = Resolver::new()
+    ///     .with(Variable::Temperature, 22.5)
+    ///     .with(Variable::DewPoint, 14.9477);
+    ///
+    /// let mixing_ratio = resolver.resolve(Variable::MixingRatio);
+    ///
+    /// assert_eq!(resolver.get(Variable::RelativeHumidity), Some(62.4));
+    /// assert_eq!(mixing_ratio, Ok(meteo_tools::common_celsius_mixing_ratio(&22.5, &62.4)));
+    /// ```
+    ///
+    /// All rules share the same saturation vapor pressure, so different paths agree:
+    ///
+    /// ```
+    /// use meteo_tools::{Resolver, Variable};
+    ///
+    /// let mut from_wet_bulb = Resolver::new()
+    ///     .with(Variable::Temperature, 25.0)
+    ///     .with(Variable::WetBulbTemperature, 20.0);
+    /// let vapor_pressure = from_wet_bulb.resolve(Variable::VaporPressure).unwrap();
+    /// let relative_humidity = from_wet_bulb.resolve(Variable::RelativeHumidity).unwrap();
+    ///
+    /// let mut from_vapor_pressure = Resolver::new()
+    ///     .with(Variable::Temperature, 25.0)
+    ///     .with(Variable::VaporPressure, vapor_pressure);
+    ///
+    /// let difference = from_vapor_pressure.resolve(Variable::RelativeHumidity).unwrap() - relative_humidity;
+    ///
+    /// assert!(difference.abs() < 0.01);
+    /// ```
+    pub fn resolve(&mut self, variable: Variable) -> Result<f64, ResolveError> {
+        if !self.can_resolve(variable) {
+            return Err(ResolveError::Insufficient(variable));
+        }
+        while self.get(variable).is_none() {
+            for rule in RULES.iter() {
+                if self.get(rule.output).is_none()
+                    && rule.inputs.iter().all(|input| self.get(*input).is_some())
+                {
+                    let value = (rule.compute)(self);
+                    self.set(rule.output, value);
+                }
+            }
+        }
+        self.get(variable)
+            .ok_or(ResolveError::Insufficient(variable))
+    }
+
+    // Value of a variable which is known to be present
+    fn value(&self, variable: Variable) -> f64 {
+        self.get(variable).unwrap_or(f64::NAN)
+    }
+
+    fn pressure(&self) -> f64 {
+        self.get(Variable::Pressure)
+            .unwrap_or(AVG_ATMOSPHERIC_PRESSURE)
+    }
+
+    // Reverts the pressure correction applied by `celsius_dew_point`
+    fn common_dew_point(&self, dew_point: f64) -> f64 {
+        match self.get(Variable::Pressure) {
+            Some(pressure) if pressure != AVG_ATMOSPHERIC_PRESSURE => {
+                let correction = 1.0
+                    - (pressure - AVG_ATMOSPHERIC_PRESSURE) / AVG_ATMOSPHERIC_PRESSURE
+                        * BAROMETRIC_EXPONENT;
+                dew_point * Float::abs(correction)
+            }
+            _ => dew_point,
+        }
+    }
+}
+
+// Inverse of the Magnus dew point formula used by `common_celsius_dew_point`
+fn magnus_relative_humidity(temperature: f64, dew_point: f64) -> f64 {
+    100.0 * saturation_vapor_pressure(&dew_point) / saturation_vapor_pressure(&temperature)
+}
+
+// Psychrometer equation e = es(Tw) - A * P * (T - Tw)
+fn psychrometric_vapor_pressure(temperature: f64, wet_bulb: f64, pressure: f64) -> f64 {
+    saturation_vapor_pressure(&wet_bulb)
+        - PSYCHROMETER_COEFFICIENT * pressure * (temperature - wet_bulb)
+}
+
+// Solves the psychrometer equation for wet bulb temperature by bisection,
+// the vapor pressure grows with wet bulb temperature, which lies below the air temperature
+fn psychrometric_wet_bulb(temperature: f64, vapor_pressure: f64, pressure: f64) -> f64 {
+    let (mut low, mut high) = (temperature - 100.0, temperature);
+    for _ in 0..64 {
+        let middle = (low + high) / 2.0;
+        if psychrometric_vapor_pressure(temperature, middle, pressure) < vapor_pressure {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.0
+}
@@ -0,0 +1,100 @@
+//! Helpers for serializing quantities as bare numbers, available with the `serde` feature.
+//!
+//! By default [`Quantity`] serializes as `{"value": 22.5, "unit": "°C"}`. Each module here can be used with
+//! `#[serde(with = "...")]` on a `Quantity` field to serialize it as a bare number in a fixed unit instead.
+//! Quantities in other units of the same dimension are converted before serialization.
+//!
+//! # Examples
+//!
+//! ```
+//! use meteo_tools::{PressureUnit, Quantity, Unit};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "meteo_tools::serialization::hectopascals")]
+//!     pressure: Quantity,
+//!     pressure_with_unit: Quantity,
+//! }
+//!
+//! let pressure = Quantity::new(29.92, Unit::Pressure(PressureUnit::InchOfMercury));
+//! let reading = Reading { pressure, pressure_with_unit: pressure };
+//!
+//! let json = serde_json::to_string(&reading).unwrap();
+//!
+//! assert_eq!(json, r#"{"pressure":1013.2076,"pressure_with_unit":{"value":29.92,"unit":"inHg"}}"#);
+//! ```
+//!
+//! Units serialize as their symbol, except Mach number at other temperature than 15 °C, which keeps
+//! the temperature in Celsius:
+//!
+//! ```
+//! use meteo_tools::{Quantity, SpeedUnit, Unit};
+//!
+//! let speed = Quantity::new(0.8, Unit::Speed(SpeedUnit::Mach(-20.0)));
+//! let json = serde_json::to_string(&speed).unwrap();
+//!
+//! assert_eq!(json, r#"{"value":0.8,"unit":"Mach(-20)"}"#);
+//! assert_eq!(serde_json::from_str::<Quantity>(&json).unwrap(), speed);
+//! ```
+
+use crate::{
+    LengthUnit, PrecipitationRateUnit, PressureUnit, Quantity, SpeedUnit, TemperatureScale, Unit,
+};
+
+fn serialize<S: serde::Serializer>(
+    quantity: &Quantity,
+    unit: Unit,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let converted = quantity
+        .convert_to(unit)
+        .map_err(serde::ser::Error::custom)?;
+    serializer.serialize_f64(converted.value)
+}
+
+fn deserialize<'de, D: serde::Deserializer<'de>>(
+    unit: Unit,
+    deserializer: D,
+) -> Result<Quantity, D::Error> {
+    let value = <f64 as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(Quantity::new(value, unit))
+}
+
+macro_rules! bare_unit {
+    ($($module:ident: $unit:expr, $name:literal;)*) => {
+        $(
+            #[doc = concat!("Serializes [`Quantity`] as a bare number of ", $name, ".")]
+            pub mod $module {
+                use super::*;
+
+                pub fn serialize<S: serde::Serializer>(
+                    quantity: &Quantity,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize(quantity, $unit, serializer)
+                }
+
+                pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Quantity, D::Error> {
+                    super::deserialize($unit, deserializer)
+                }
+            }
+        )*
+    };
+}
+
+bare_unit! {
+    celsius: Unit::Temperature(TemperatureScale::Celsius), "degrees Celsius";
+    fahrenheit: Unit::Temperature(TemperatureScale::Fahrenheit), "degrees Fahrenheit";
+    kelvin: Unit::Temperature(TemperatureScale::Kelvin), "Kelvin";
+    hectopascals: Unit::Pressure(PressureUnit::Hectopascal), "hPa";
+    inches_of_mercury: Unit::Pressure(PressureUnit::InchOfMercury), "inHg";
+    meters_per_second: Unit::Speed(SpeedUnit::MetersPerSecond), "m/s";
+    kilometers_per_hour: Unit::Speed(SpeedUnit::KilometersPerHour), "km/h";
+    knots: Unit::Speed(SpeedUnit::Knots), "knots";
+    meters: Unit::Length(LengthUnit::Meter), "meters";
+    millimeters: Unit::Length(LengthUnit::Millimeter), "millimeters";
+    millimeters_per_hour: Unit::PrecipitationRate(PrecipitationRateUnit::MillimetersPerHour), "mm/h";
+}